@@ -1,20 +1,41 @@
 use std::cell::RefCell;
+use std::collections::HashSet;
 use std::rc::Rc;
 use std::fmt::Debug;
 use std::sync::{Arc, Mutex};
+use std::sync::mpsc::Sender;
 use std::usize;
 
 // modules for MinerRobot
 mod util;
+pub use util::aggregator::aggregator::{EventAggregator, EventStats, TickStats};
+pub use util::coverage::coverage::Coverage;
+pub use util::discovery::discovery::ScanReport;
+pub use util::goal_events::goal_events::{GoalCallback, GoalEvent, GoalEventKind, GoalRecord};
+pub use util::grid::grid::{Grid, Pos};
+pub use util::known_map::known_map::KnownMap;
+pub use util::ledger::ledger::{ActionKind, EnergyLedger, LedgerEntry, LedgerSummary, Purpose};
+pub use util::logger::logger::{init_logger, MinerLogger};
+pub use util::bridge::debug::BridgeJob;
+pub use util::builder::builder::MinerRobotBuilder;
+pub use util::config::config::{ConfigError, MinerConfig};
+pub use util::content::content::{ContentIndex, QuantityFilter};
+pub use util::error::error::MinerError;
+pub use util::recharge::recharge::{CustomRecharge, DynamoRecharge, RechargeOutcome, RechargeStrategy, WaitRecharge};
+pub use util::recorder::recorder::{RecordHeader, RecordLine, Recorder, Replayer, TickRecord};
+pub use util::snapshot::snapshot::RobotSnapshot;
+pub use util::state::state::StateTransition;
+pub use util::summary::summary::{GameOverReason, RunSummary};
+pub use util::watchdog::watchdog::{ProgressSample, Watchdog};
+pub use util::strategy::strategy::{CustomStrategy, DefaultBridgeStrategy, MinerStrategy, StrategyContext};
 
 // robotics lib
 use robotics_lib::event::events::Event;
-use robotics_lib::interface::destroy;
 use robotics_lib::interface::Direction;
 use robotics_lib::runner::backpack::BackPack;
 use robotics_lib::runner::{Robot, Runnable};
 use robotics_lib::world::coordinates::Coordinate;
-use robotics_lib::world::tile::{Content, Tile};
+use robotics_lib::world::tile::Content;
 use robotics_lib::energy::Energy;
 use robotics_lib::world::World;
 
@@ -22,32 +43,70 @@ use robotics_lib::world::World;
 use bessie::bessie::{road_paving_machine, RpmError, State};
 use bob_lib::tracker::GoalTracker;
 use colored::Colorize;
+use log::{info, trace, warn};
 use OwnerSheeps_Sound_Tool::functions::weather_sounds::weather_sound;
 use pmp_collect_all::CollectAll;
 use robotics_lib::utils::LibError;
-use rust_and_furious_dynamo::dynamo::Dynamo;
 use sense_and_find_by_rustafariani::{Lssf};
 use spyglass::spyglass::Spyglass;
+use serde::{Deserialize, Serialize};
+use util::error::error::rpm_error_message;
+use util::content::content::same_kind;
 
 
+// default values of MinerConfig
+
 // const used to set the goal quantity
 const GOAL_QUANTITY: u32 = 5;
+// amount of ticks after which a goal that has not been completed expires
+const GOAL_TIMEOUT: usize = 200;
 
-// const that are used for the spyglass
+// const that are used for the spyglass, the budget and threshold of each scan are adapted from these values
 const ENERGY_BUDGET: usize = 300;
 const THRESHOLD: f64 = 0.5;
+const MIN_DISCOVERY_BUDGET: usize = 50;
+const MIN_THRESHOLD: f64 = 0.1;
+const MAX_THRESHOLD: f64 = 0.9;
 
-// scan distance and increase (indicates how much the distance will increase if changed)
+// distance of the scans
 pub const SCAN_DISTANCE: usize = 10;
-const SCAN_INCREASE: usize = 10;
 
 // energy threshold, the robot's energy cannot get lower than the threshold
 const MIN_ENERGY: usize = 100;
+// in fair-play mode the robot waits until its energy gets back to this level
+const RESUME_ENERGY: usize = 300;
+
+// range of the CollectAll tool and direction of the road paving machine, used when there is nothing left to collect
+const RANGE: usize = 2;
+const DIRECTION: &str = "up";
+
+// amount of closest tiles whose path cost is computed when the robot looks for content
+const CONTENT_CANDIDATES: usize = 8;
+
+// amount of times the robot checks the bridge points before giving up
+const MAX_BRIDGE_ITERATIONS: usize = 10;
+
+// amount of ticks without progress after which the robot is stuck, and amount of recoveries before giving up
+const WATCHDOG_WINDOW: usize = 15;
+const MAX_RECOVERIES: usize = 3;
 
-#[derive(Debug)]
+// amount of ticks of events kept by the event aggregator
+const EVENT_WINDOW: usize = 100;
+
+// most verbose level of the logger, the map is only printed at the debug level
+const LOG_LEVEL: &str = "info";
+
+/// The states of the robot, the transitions between them are checked by RobotState::can_transition_to
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub enum RobotState {
-    CollectingRocks,
-    PavingBridge
+    Exploring,
+    TravelingToResource,
+    Collecting,
+    TravelingToBridgeStart,
+    Paving,
+    Recharging,
+    Stuck,
+    Finished
 }
 
 // struct of the robot
@@ -56,36 +115,59 @@ pub struct MinerRobot {
     pub name: String,
     pub goal_tracker: GoalTracker,
     pub rocks_collected: usize,
+    pub rocks_mined: usize,
+    pub bridges_built: usize,
     pub scan_distance: usize,
     pub lssf: Lssf,
     pub world_scanned: bool,
+    pub spyglass: Option<Spyglass>,
+    pub scan_budget: usize,
+    pub scan_threshold: f64,
+    pub scan_reports: Vec<ScanReport>,
+    pub explored_frontiers: HashSet<Pos>,
+    explored_frontiers_version: Option<usize>,
+    pub coverage: Coverage,
+    known_map: KnownMap,
+    lssf_map_version: Option<usize>,
+    pub energy_ledger: EnergyLedger,
+    pub events: EventAggregator,
     pub state: RobotState,
+    pub state_history: Vec<StateTransition>,
+    pub resource_target: Option<Pos>,
+    pub skipped_targets: HashSet<Pos>,
+    pub bridge_job: Option<BridgeJob>,
+    pub current_path: Vec<Pos>,
+    pub watchdog: Watchdog,
+    bridge_iterations: usize,
+    resume_state: Option<RobotState>,
+    pub waiting_for_energy: bool,
+    recharge_strategy: Option<Box<dyn RechargeStrategy>>,
+    strategy: Option<Box<dyn MinerStrategy>>,
+    recorder: Option<Recorder>,
     pub game_over: Arc<Mutex<bool>>,
-    pub coordinates: Arc<Mutex<(usize, usize)>>
+    pub game_over_reason: Option<GameOverReason>,
+    pub run_summary: Option<RunSummary>,
+    pub coordinates: Arc<Mutex<(usize, usize)>>,
+    pub snapshot: Arc<Mutex<RobotSnapshot>>,
+    pub tick: usize,
+    pub goal_records: Vec<GoalRecord>,
+    pub goals_completed: usize,
+    goal_callbacks: Vec<GoalCallback>,
+    goal_senders: Vec<Sender<GoalEvent>>,
+    pub config: MinerConfig
 }
 
 impl MinerRobot {
     /// Constructors
     ///
 
-    /// Creates a new instance of MinerRobot
+    /// Creates a new instance of MinerRobot with the default options
     ///
     /// # Returns
     ///
     /// A new instance of Self
     pub fn new() -> Self {
-        Self {
-            robot: Robot::new(),
-            name: String::from("The default miner"),
-            goal_tracker: GoalTracker::new(),
-            rocks_collected: 0,
-            scan_distance: SCAN_DISTANCE,
-            lssf: Lssf::new(),
-            world_scanned: false,
-            state: RobotState::CollectingRocks,
-            game_over: Arc::new(Mutex::from(false)),
-            coordinates: Arc::new(Mutex::new((0,0)))
-        }
+        MinerRobotBuilder::new().build().expect("The default options are valid")
     }
     /// Creates a new instance of MinerRobot given its name
     ///
@@ -96,19 +178,16 @@ impl MinerRobot {
     /// # Returns
     ///
     /// A new instance of Self
+    ///
+    /// # Notes
+    ///
+    /// If the name is empty the robot gets the default name, use builder() to get the ConfigError instead
     pub fn new_name(name: String) -> Self {
-        Self {
-            robot: Robot::new(),
-            name,
-            goal_tracker: GoalTracker::new(),
-            rocks_collected: 0,
-            scan_distance: SCAN_DISTANCE,
-            lssf: Lssf::new(),
-            world_scanned: false,
-            state: RobotState::CollectingRocks,
-            game_over: Arc::new(Mutex::from(false)),
-            coordinates: Arc::new(Mutex::new((0,0)))
-        }
+        MinerRobotBuilder::new().name(name).build().unwrap_or_else(|_| Self::new())
+    }
+    /// Returns a builder to set the options of a new MinerRobot
+    pub fn builder() -> MinerRobotBuilder {
+        MinerRobotBuilder::new()
     }
 
     /// Utility methods
@@ -118,7 +197,6 @@ impl MinerRobot {
     ///
     /// # Arguments
     ///
-    ///  * `world` - the world
     ///  * `distance` - the distance from the center within which the discovery is to take place
     ///  * `energy_budget` - the maximum amount of energy the tool can use
     ///  * `threshold` - value that determines whether to discover a certain area
@@ -126,12 +204,12 @@ impl MinerRobot {
     /// # Returns
     ///
     /// An instance of the newly created Spyglass
-    fn create_spyglass(&mut self, world: &World, distance: usize, energy_budget: usize, threshold: f64) -> Spyglass {
+    fn create_spyglass(&mut self, distance: usize, energy_budget: usize, threshold: f64) -> Spyglass {
         let mut spyglass = Spyglass::new_default(
             self.robot.coordinate.get_row(),
             self.robot.coordinate.get_col(),
             distance,
-            self.get_map(world).len()
+            self.known_map.rows().max(self.known_map.cols())
         );
         spyglass.set_energy_budget(Some(energy_budget));
         spyglass.set_view_threshold(threshold);
@@ -142,17 +220,24 @@ impl MinerRobot {
     ///
     /// # Arguments
     ///
-    /// * `map` - the map of the discovered world
+    /// * `world` - the world
     /// * `row` - the row coordinate from which we want the cost to be updated
     /// * `col` - the column coordinate from which we want the cost to be updated
-    fn update_lssf_map_and_cost(&mut self, world: &mut World, map: &Vec<Vec<Option<Tile>>>, row: usize, col: usize) {
-        self.lssf.update_map(&map);
+    ///
+    /// # Notes
+    ///
+    /// The Lssf map is only copied from the KnownMap when the known map changed since the last update
+    fn update_lssf_map_and_cost(&mut self, world: &mut World, row: usize, col: usize) {
+        if self.lssf_map_version != Some(self.known_map.get_version()) {
+            self.lssf.update_map(&self.known_map.to_lssf_map());
+            self.lssf_map_version = Some(self.known_map.get_version());
+        }
         match self.lssf.update_cost(row,col) {
             Ok(()) => {
-                println!("Lssf cost updated successfully")
+                trace!(target: "movement", "Lssf cost updated successfully")
             },
             Err(e) => {
-                self.catch_lib_error(world,e);
+                self.catch_lib_error(world,&e);
             }
         }
     }
@@ -162,14 +247,22 @@ impl MinerRobot {
     ///
     ///  * `world` - the world
     ///  * `direction` - the direction in which the robot starts paving the road
-    fn collect_rocks_inline(&mut self, world: &mut World, direction: Direction) {
-        match road_paving_machine(self,world,direction,State::GetStones) {
+    ///
+    /// # Returns
+    ///
+    /// A MinerError if the road paving machine fails
+    fn collect_rocks_inline(&mut self, world: &mut World, direction: Direction) -> Result<(), MinerError> {
+        let backpack = self.robot.backpack.get_contents().clone();
+        let rocks_before = self.get_backpack_quantity(&Content::Rock(0));
+        let result = self.metered(ActionKind::Tool, Purpose::Collecting, |robot| road_paving_machine(robot,world,direction,State::GetStones));
+        match result {
             Ok(()) => {
-                println!("The Process ended correctly and we made a Road!")
-            }
-            Err(e) => {
-                self.catch_rpm_error(world,e);
+                info!(target: "actions", "The Process ended correctly and we made a Road!");
+                self.update_rock_count(rocks_before);
+                self.report_backpack_gains(backpack);
+                Ok(())
             }
+            Err(e) => Err(self.rpm_failure(world, e))
         }
     }
     /// Collects all the content around the robot
@@ -179,34 +272,129 @@ impl MinerRobot {
     /// * `world` - the world
     /// * `range` - the range around the robot
     fn collect_all(&mut self, world: &mut World, range: usize) {
-        CollectAll::collect_all(self,world,range);
+        let backpack = self.robot.backpack.get_contents().clone();
+        let rocks_before = self.get_backpack_quantity(&Content::Rock(0));
+        self.metered(ActionKind::Tool, Purpose::Collecting, |robot| CollectAll::collect_all(robot,world,range));
+        self.update_rock_count(rocks_before);
+        self.report_backpack_gains(backpack);
     }
     /// Recharges the energy if the energy level goes below the minimum threshold
     ///
     /// # Arguments
     ///
     /// * `world` - the world
-    fn manage_energy(&mut self, world: &mut World) {
-        if self.robot.energy.get_energy_level() < MIN_ENERGY {
+    ///
+    /// # Returns
+    ///
+    /// A bool stating whether the robot has enough energy to go on, it is false while waiting in fair-play mode
+    fn manage_energy(&mut self, world: &mut World) -> bool {
+        if self.robot.energy.get_energy_level() < self.config.min_energy {
             self.recharge_energy(world);
         }
+        !self.waiting_for_energy
     }
-    /// Recharges the robot's energy by calling the Dynamo tool
+    /// Returns the recharge strategy used when none is set
+    ///
+    /// # Arguments
+    ///
+    /// * `fair_play` - whether the fair-play mode is enabled
+    ///
+    /// # Returns
+    ///
+    /// WaitRecharge in fair-play mode, DynamoRecharge otherwise
+    pub(crate) fn default_recharge_strategy(fair_play: bool) -> Box<dyn RechargeStrategy> {
+        if fair_play {
+            Box::new(WaitRecharge)
+        } else {
+            Box::new(DynamoRecharge::new())
+        }
+    }
+    /// Recharges the robot's energy by calling the selected recharge strategy
     ///
     /// # Arguments
     ///
     /// * `world` - the world
-    fn recharge_energy(&mut self, world: &mut World) {
-        let _= destroy(self, world, Direction::Down);
-        *self.get_energy_mut() = Dynamo::update_energy();
+    ///
+    /// # Notes
+    ///
+    /// If the strategy asks to wait, the robot idles until robotics_lib has recharged its energy
+    pub(crate) fn recharge_energy(&mut self, world: &mut World) {
+        let mut strategy = match self.recharge_strategy.take() {
+            Some(strategy) => strategy,
+            None => return
+        };
+        let energy = self.robot.energy.get_energy_level();
+        let outcome = strategy.recharge(self, world);
+        self.energy_ledger.record_refill(self.robot.energy.get_energy_level().saturating_sub(energy));
+        match outcome {
+            RechargeOutcome::Refilled => self.waiting_for_energy = false,
+            RechargeOutcome::Waiting => {
+                info!(target: "energy", "{} recharge, the robot waits for its energy to recharge", strategy.name());
+                self.waiting_for_energy = true;
+            },
+            RechargeOutcome::Failed => warn!(target: "energy", "{} recharge failed", strategy.name())
+        }
+        self.recharge_strategy = Some(strategy);
     }
-    /// Updates self's rock count
-    fn update_rock_count(&mut self) {
-        let contents = self.robot.backpack.get_contents();
-        if let Some(&rocks_amount) = contents.get(&Content::Rock(0)) {
-            self.rocks_collected = rocks_amount;
+    /// Checks whether the robot is waiting for its energy to recharge
+    ///
+    /// # Returns
+    ///
+    /// A bool, the robot stops waiting once its energy is back to the resume energy of the configuration
+    pub(crate) fn is_waiting_for_energy(&mut self) -> bool {
+        if self.waiting_for_energy && self.robot.energy.get_energy_level() >= self.config.resume_energy {
+            self.waiting_for_energy = false;
+        }
+        self.waiting_for_energy
+    }
+    /// Shares the robot's coordinates with the other threads
+    ///
+    /// # Notes
+    ///
+    /// If a reader panicked while holding the lock the coordinates are not published anymore, but the robot keeps running
+    fn publish_coordinates(&mut self) {
+        if let Ok(mut coordinate) = self.coordinates.lock() {
+            *coordinate = (self.get_coordinate().get_row(), self.get_coordinate().get_col());
         }
     }
+    /// Updates self's rock count after an action that collects rocks
+    ///
+    /// # Arguments
+    ///
+    /// * `rocks_before` - the amount of rocks in the backpack before the action
+    ///
+    /// # Notes
+    ///
+    /// Only the rocks gained by the action are counted as mined
+    fn update_rock_count(&mut self, rocks_before: usize) {
+        let rocks_amount = self.get_backpack_quantity(&Content::Rock(0));
+        self.rocks_mined += rocks_amount.saturating_sub(rocks_before);
+        self.rocks_collected = rocks_amount;
+    }
+    /// Reads self's rock count from the backpack, without counting any rock as mined
+    ///
+    /// # Notes
+    ///
+    /// Used after paving, since the rocks actually put can be less than the estimated cost of the bridge
+    fn sync_rock_count(&mut self) {
+        self.rocks_collected = self.get_backpack_quantity(&Content::Rock(0));
+    }
+    /// Returns the amount of a content stored in the robot's backpack
+    ///
+    /// # Arguments
+    ///
+    /// * `content` - the content to look for, its quantity is ignored
+    ///
+    /// # Returns
+    ///
+    /// The amount of the given content in the backpack
+    pub fn get_backpack_quantity(&self, content: &Content) -> usize {
+        self.robot.backpack.get_contents()
+            .iter()
+            .filter(|(c, _)| same_kind(c, content))
+            .map(|(_, quantity)| *quantity)
+            .sum()
+    }
     /// Returns the robot's coordinates
     ///
     /// # Returns
@@ -215,25 +403,29 @@ impl MinerRobot {
     pub fn get_coordinates(&self) -> (usize,usize) {
         (self.robot.coordinate.get_row(),self.robot.coordinate.get_col())
     }
+    /// Returns the robot's coordinates as a Pos
+    pub fn get_position(&self) -> Pos {
+        Pos::from(self.get_coordinates())
+    }
     /// Catches the LibError
     ///
     /// # Arguments
     ///
     /// * `world` - the world
     /// * `error` - the LibError
-    pub fn catch_lib_error(&mut self, world: &mut World, error: LibError) {
+    pub fn catch_lib_error(&mut self, world: &mut World, error: &LibError) {
         match error {
             LibError::NotEnoughEnergy => {
-                println!("Not enough energy, the robot will get its energy refilled");
+                warn!(target: "energy", "Not enough energy, the robot will recharge");
                 self.recharge_energy(world);
             },
-            LibError::OutOfBounds => println!("Out of bounds"),
-            LibError::NoContent => println!("No content"),
-            LibError::NotEnoughSpace(remainder) => println!("Not enough space: {}", remainder),
-            LibError::CannotDestroy => println!("Cannot destroy"),
-            LibError::NotCraftable => println!("Can't craft this item"),
-            LibError::NoMoreDiscovery => println!("Not enough discoverable tiles"),
-            _ => println!("Generic error: {:?}", error)
+            LibError::OutOfBounds => warn!(target: "actions", "Out of bounds"),
+            LibError::NoContent => warn!(target: "actions", "No content"),
+            LibError::NotEnoughSpace(remainder) => warn!(target: "actions", "Not enough space: {}", remainder),
+            LibError::CannotDestroy => warn!(target: "actions", "Cannot destroy"),
+            LibError::NotCraftable => warn!(target: "actions", "Can't craft this item"),
+            LibError::NoMoreDiscovery => warn!(target: "discovery", "Not enough discoverable tiles"),
+            _ => warn!(target: "actions", "Generic error: {:?}", error)
         }
     }
     /// Catches the RpmError for the road paving machine
@@ -242,22 +434,31 @@ impl MinerRobot {
     ///
     /// * `world` - the world
     /// * `error` - the RpmError
-    pub fn catch_rpm_error(&mut self, world: &mut World, error: RpmError) {
+    pub fn catch_rpm_error(&mut self, world: &mut World, error: &RpmError) {
         match error {
             RpmError::NotEnoughEnergy => {
-                println!("Not enough energy, the robot will get its energy refilled");
+                warn!(target: "energy", "Not enough energy, the robot will recharge");
                 self.recharge_energy(world);
             },
-            RpmError::CannotPlaceHere => println!("Cannot place content on the current tile"),
-            RpmError::OutOfBounds => println!("Out of bounds"),
-            RpmError::NotEnoughMaterial => println!("Not enough material"),
-            RpmError::NoRockHere => println!("No rock here"),
-            RpmError::MustDestroyContentFirst => println!("Must destroy content first"),
-            RpmError::UndefinedError => println!("Undefined error")
+            _ => warn!(target: "actions", "{}", rpm_error_message(error))
         }
     }
     /// Sets the game_over value to true, ending the game
-    pub fn game_is_over(&mut self) {
+    ///
+    /// # Arguments
+    ///
+    /// * `reason` - why the game ended
+    /// * `final_state` - the state the robot was in when the game ended
+    ///
+    /// # Notes
+    ///
+    /// The goals that are still active are reported as failed, the energy summary gets printed and the RunSummary is created
+    pub fn game_is_over(&mut self, reason: GameOverReason, final_state: RobotState) {
+        self.fail_pending_goals();
+        self.print_energy_summary();
+        self.game_over_reason = Some(reason.clone());
+        self.write_run_summary(reason, final_state);
+
         let mut game_over_lock = self.game_over.lock().unwrap();
 
         // Modify the boolean value
@@ -267,32 +468,45 @@ impl MinerRobot {
 
 impl Runnable for MinerRobot {
     fn process_tick(&mut self, world: &mut World) {
+        self.tick += 1;
         weather_sound(world);
 
-        // scanning the area around the robot once
-        self.scan_world(world,self.scan_distance);
+        // reading the known map once, the events keep it up to date during the tick
+        self.refresh_known_map(world);
 
         // managing the creation/deletion of goals
         self.handle_goals();
 
-        // self.print_discovered_tiles_content(&world);
-        self.print_discovered_tiles_tile_type(&world);
+        // measuring how much of the world has been discovered
+        self.update_coverage();
 
-        // moving and collecting rocks
-        self.move_and_collect_content(world, Content::Rock(1));
+        // self.print_discovered_tiles_content();
+        self.print_discovered_tiles_tile_type();
+        self.print_coverage();
 
-        // building a bridge if possible
-        self.pave_bridge(world);
+        // doing the work decided by the strategy
+        self.run_strategy(world);
 
-        // if the robot's energy drops below a certain threshold it recharges
-        self.manage_energy(world);
+        // checking that the robot is still making progress
+        self.check_progress();
 
-        let mut coordinate = self.coordinates.lock().unwrap();
-        *coordinate = (self.get_coordinate().get_row(), self.get_coordinate().get_col());
+        self.publish_coordinates();
+        self.publish_snapshot();
+        self.record_tick();
     }
     #[allow(dead_code)]
     fn handle_event(&mut self, event: Event) {
-        println!("{:?}", event);
+        trace!(target: "events", "{:?}", event);
+        self.events.record(self.tick, self.state, &event);
+        match &event {
+            Event::Moved(tile, coordinates) | Event::TileContentUpdated(tile, coordinates) => {
+                self.known_map.update_tile(Pos::from(*coordinates), tile.clone());
+            },
+            _ => {}
+        }
+        if let Some(recorder) = self.recorder.as_mut() {
+            recorder.record_event(format!("{:?}", event));
+        }
     }
     #[allow(dead_code)]
     fn get_energy(&self) -> &Energy {
@@ -327,14 +541,16 @@ impl Debug for MinerRobot {
                format!("{}'s STATS\n\
                - Coordinates: {:?} \n\
                - Energy: {:?} \n\
-               - Goal_tracker: \n \
-                    \t - Goals: {:?} \n \
+               - Goals: \n \
+                    \t - Active: {:?} \n \
                     \t - Completed: {} \n\
                - Rocks collected: {:?} \n\
+               - Coverage: {:.1}% ({} tiles, frontier length: {}) \n\
                - State: {:?} \n",
                        self.name, self.get_coordinates(), self.robot.energy.get_energy_level(),
-                       self.goal_tracker.get_goals(), self.goal_tracker.get_completed_number(),
-                       self.rocks_collected, self.state
+                       self.goal_records, self.goals_completed,
+                       self.rocks_collected, self.coverage.discovered_percentage, self.coverage.discovered_tiles,
+                       self.coverage.frontier_length, self.state
                ).green()
         )
     }
@@ -1,12 +1,13 @@
+use std::collections::HashSet;
 use std::env;
 use std::path;
 use std::path::PathBuf;
 use std::ptr::null;
 use std::sync::{Arc, Mutex};
 use std::thread::sleep;
-use std::time::Duration;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
 use colored::Colorize;
-use ggez::{Context, ContextBuilder, GameResult};
+use ggez::{Context, ContextBuilder, GameError, GameResult};
 use ggez::graphics::{self, Canvas, Color, DrawParam, Image, Text};
 use ggez::event::{self, EventHandler};
 use ggez::glam::Vec2;
@@ -23,10 +24,16 @@ use robotics_lib::event::events::Event;
 use robotics_lib::runner::backpack::BackPack;
 use robotics_lib::world::coordinates::Coordinate;
 use robotics_lib::world::World;
+use log::{debug, error, trace, warn};
 
 
-use holy_crab_best_path::MinerRobot;
+use holy_crab_best_path::{init_logger, MinerConfig, MinerRobot, Replayer, RobotSnapshot};
 const SCREEN_SIZE: f32 = 1600.;
+// the world the robot runs on, and the start of the name of the recordings, which end with the time of the run
+const WORLD_FILE: &str = "world/bridge2.bin";
+const RECORD_PREFIX: &str = "world/bridge2";
+// frames between two ticks of a replay
+const REPLAY_FRAMES_PER_TICK: usize = 30;
 
 
 
@@ -78,7 +85,9 @@ fn run_my_game()->GameResult{
     // Create an instance of your event handler.
     // Usually, you should provide it with the Context object to
     // use when setting your game up.
-    let my_game = MyGame::new(&mut ctx)?;
+    // `--replay <file>` draws a recording instead of running the robot
+    let replay_file = env::args().skip_while(|arg| arg != "--replay").nth(1);
+    let my_game = MyGame::new(&mut ctx, replay_file)?;
 
     // Run!
     event::run(ctx, event_loop, my_game);
@@ -104,7 +113,40 @@ struct MyGame {
     size: f32,
     zoom: f32,
     offset: (usize, usize),
-    coordinates: Arc<Mutex<(usize, usize)>>
+    snapshot: Arc<Mutex<RobotSnapshot>>,
+    replayer: Option<Replayer>
+}
+
+impl MyGame {
+    /// Returns the amount of rows of the map
+    fn rows(&self) -> usize {
+        self.map.len()
+    }
+    /// Returns the amount of columns of the map, 0 if the map is empty
+    fn cols(&self) -> usize {
+        self.map.first().map_or(0, |row| row.len())
+    }
+    /// Applies the next tick of the replay to the map, if there is a replay
+    fn replay_next_tick(&mut self) {
+        let replayer = match self.replayer.as_mut() {
+            Some(replayer) => replayer,
+            None => return
+        };
+        if let Some(record) = replayer.next_tick() {
+            trace!(target: "gui", "Replaying tick {}: {:?}, energy: {}", record.tick, record.coordinates, record.energy);
+        }
+        for (row, tiles_row) in replayer.get_known_map().iter().enumerate() {
+            for (col, tile) in tiles_row.iter().enumerate() {
+                if let (Some(tile), Some(cell)) = (tile, self.map.get_mut(row).and_then(|r| r.get_mut(col))) {
+                    cell.0 = tile.clone();
+                }
+            }
+        }
+    }
+    /// Returns the amount of tiles that fit on the screen along one axis
+    fn visible_tiles(&self) -> usize {
+        (SCREEN_SIZE/self.size/self.zoom) as usize
+    }
 }
 
 fn choose_image<'a>(game: &'a MyGame, map: &'a Vec<Vec<(Tile,usize)>>, coordinate: (usize, usize)) -> &'a Image {
@@ -116,7 +158,7 @@ fn choose_image<'a>(game: &'a MyGame, map: &'a Vec<Vec<(Tile,usize)>>, coordinat
         TileType::Snow => &game.snow_image,
         TileType::DeepWater => &game.deep_water_image,
         TileType::Sand => {
-            if coordinate.1+1 == map[0].len(){
+            if coordinate.1+1 >= map[coordinate.0].len(){
                 return &game.sand_image;
             }
             let tile_down = &map[coordinate.0][coordinate.1+1];
@@ -134,7 +176,7 @@ fn choose_image<'a>(game: &'a MyGame, map: &'a Vec<Vec<(Tile,usize)>>, coordinat
         },
         TileType::Hill => {
 
-            if coordinate.1+1 == map[0].len(){
+            if coordinate.1+1 >= map[coordinate.0].len(){
                 return if tile.1 > 8 {
                     &game.hill_image1
                 } else {
@@ -162,7 +204,7 @@ fn choose_image<'a>(game: &'a MyGame, map: &'a Vec<Vec<(Tile,usize)>>, coordinat
 }
 
 impl MyGame {
-    pub fn new(ctx: &mut Context) -> GameResult<MyGame> {
+    pub fn new(ctx: &mut Context, replay_file: Option<String>) -> GameResult<MyGame> {
         // Load/create resources such as images here.
         let tile_grass = Tile{
             tile_type: TileType::Grass,
@@ -184,53 +226,34 @@ impl MyGame {
         let street_image = Image::from_path(ctx, "/tiles/Map_tile_23.png")?;
         let down_hill = Image::from_path(ctx, "/tiles/down_hill.png")?;
 
-        let gui_start = false;
-        let path = PathBuf::new().join("world/bridge2.bin");
-        let mut world_generator = WorldgeneratorUnwrap::init(gui_start, Some(path));
-        let world = world_generator.gen();
-
-        let map = world.0;
-        let mut option_map: Vec<Vec<(Tile, usize)>> = vec![];
-        let mut rng = rand::thread_rng();
-
-        /**/
-
-        let robot_thread =  MinerRobot::new();
-        let coordinates = robot_thread.coordinates.clone();
-
-
-        thread::spawn(move || {
-            let game_over = robot_thread.game_over.clone();
-            let robot_box = Box::new(robot_thread);
-
-            let run = Runner::new( robot_box, &mut world_generator);
-            match run {
-                Ok(mut running) => {
-                    loop{
-                        let _ = running.game_tick();
-                        let mut game_over_ref = game_over.lock().unwrap();
-
+        // the configuration is read from HOLY_CRAB_CONFIG and from the HOLY_CRAB_* variables
+        let (config, config_error) = match MinerConfig::load(None) {
+            Ok(config) => (config, None),
+            Err(e) => (MinerConfig::default(), Some(e))
+        };
+        if let Err(e) = init_logger(&config) {
+            eprintln!("{}, the log is disabled", e);
+        }
+        if let Some(e) = config_error {
+            warn!(target: "gui", "{}, using the default configuration", e);
+        }
 
-                        // if the game_over value is true then the game ends
-                        if *game_over_ref {
-                            break;
-                        }
-                        sleep(Duration::from_millis(2000))
-                    };
-                }
-                Err(e) => {
-                    println!("Error in runnable - main");
-                    println!("{:?}", e);
-                }
+        let (map, snapshot, replayer) = match replay_file {
+            // the viewer draws the recording instead of running the robot
+            Some(path) => {
+                let replayer = Replayer::open(&path)
+                    .map_err(|e| GameError::CustomError(format!("Cannot open the recording {}: {}", path, e)))?;
+                let map = known_map_to_tiles(replayer.get_known_map());
+                (map, Arc::new(Mutex::new(RobotSnapshot::default())), Some(replayer))
             }
-        });
-
-        //let data = receiver.recv().unwrap();
-
-
-
-        /**/
+            None => {
+                let (map, snapshot) = start_robot(config);
+                (map, snapshot, None)
+            }
+        };
 
+        let mut rng = rand::thread_rng();
+        let mut option_map: Vec<Vec<(Tile, usize)>> = vec![];
         for row in map{
             let mut mrow = vec![];
             for tile in row{
@@ -259,7 +282,8 @@ impl MyGame {
             size: 32.,
             zoom: 2.,
             offset: (0,0),
-            coordinates
+            snapshot,
+            replayer
         };
 
 
@@ -267,6 +291,76 @@ impl MyGame {
     }
 }
 
+/// Generates the world and runs the robot on another thread
+///
+/// # Arguments
+///
+/// * `config` - the configuration of the robot
+///
+/// # Returns
+///
+/// The generated world and the snapshot published by the robot after every tick
+///
+/// # Notes
+///
+/// The run is recorded next to the world unless the configuration sets its own record_file,
+/// every run gets its own recording so the previous ones are kept
+fn start_robot(config: MinerConfig) -> (Vec<Vec<Tile>>, Arc<Mutex<RobotSnapshot>>) {
+    let gui_start = false;
+    let path = PathBuf::new().join(WORLD_FILE);
+    let mut world_generator = WorldgeneratorUnwrap::init(gui_start, Some(path));
+    let world = world_generator.gen();
+
+    let mut builder = MinerRobot::builder();
+    if config.record_file.is_none() {
+        let millis = SystemTime::now().duration_since(UNIX_EPOCH).map(|d| d.as_millis()).unwrap_or(0);
+        builder = builder.record_file(format!("{}-{}.jsonl", RECORD_PREFIX, millis));
+    }
+    let robot_thread = builder
+        .config(config)
+        .build()
+        .expect("Invalid robot options");
+    let snapshot = robot_thread.snapshot.clone();
+
+    thread::spawn(move || {
+        let game_over = robot_thread.game_over.clone();
+        let robot_box = Box::new(robot_thread);
+
+        let run = Runner::new( robot_box, &mut world_generator);
+        match run {
+            Ok(mut running) => {
+                loop{
+                    let _ = running.game_tick();
+                    let game_over_ref = game_over.lock().unwrap();
+
+                    // if the game_over value is true then the game ends
+                    if *game_over_ref {
+                        break;
+                    }
+                    sleep(Duration::from_millis(2000))
+                };
+            }
+            Err(e) => {
+                error!(target: "gui", "Error in runnable - main");
+                error!(target: "gui", "{:?}", e);
+            }
+        }
+    });
+
+    (world.0, snapshot)
+}
+
+/// Converts the map of a recording into tiles, the unknown tiles are drawn as deep water
+fn known_map_to_tiles(map: &[Vec<Option<Tile>>]) -> Vec<Vec<Tile>> {
+    map.iter()
+        .map(|row| row.iter().map(|tile| tile.clone().unwrap_or(Tile {
+            tile_type: TileType::DeepWater,
+            content: Content::None,
+            elevation: 0
+        })).collect())
+        .collect()
+}
+
 impl EventHandler for MyGame {
     fn update(&mut self, ctx: &mut Context) -> GameResult {
         // Update code here...
@@ -277,7 +371,7 @@ impl EventHandler for MyGame {
             self.zoom -= 0.1;
         }
         if ctx.keyboard.is_key_pressed(KeyCode::Down) {
-            if self.offset.1 < self.map.len() - (SCREEN_SIZE/self.size/self.zoom) as usize{
+            if self.offset.1 < self.cols().saturating_sub(self.visible_tiles()){
                 self.offset.1 += 1;
             }
         }
@@ -292,7 +386,7 @@ impl EventHandler for MyGame {
             }
         }
         if ctx.keyboard.is_key_pressed(KeyCode::Right) {
-            if self.offset.0 < self.map.len() - (SCREEN_SIZE/self.size/self.zoom) as usize{
+            if self.offset.0 < self.rows().saturating_sub(self.visible_tiles()){
                 self.offset.0 += 1;
             }
         }
@@ -304,7 +398,10 @@ impl EventHandler for MyGame {
         let mut canvas = graphics::Canvas::from_frame(ctx, graphics::Color::from([0.1, 0.2, 0.3, 1.0]));
         // Draw code here...
 
-        println!("************{:?}", self.coordinates.lock().unwrap());
+        if let Ok(snapshot) = self.snapshot.lock() {
+            trace!(target: "gui", "Robot coordinates: {:?}, state: {:?}, energy: {}",
+                   snapshot.coordinates, snapshot.state, snapshot.energy);
+        }
 
         // TODO: separare in un file la grafica dal main
         // TODO: collegare un bot
@@ -322,19 +419,27 @@ impl EventHandler for MyGame {
 
         self.frames += 1;
         if (self.frames % 100) == 0 {
-            println!("FPS: {}", ctx.time.fps());
+            debug!(target: "gui", "FPS: {}", ctx.time.fps());
+        }
+        if (self.frames % REPLAY_FRAMES_PER_TICK) == 0 {
+            self.replay_next_tick();
         }
         Ok(())
     }
 }
 
 fn draw_map(mygame: &MyGame, canvas: &mut Canvas ){
+    // the tiles the robot walked on during the replay are tinted
+    let trajectory: HashSet<(usize, usize)> = match &mygame.replayer {
+        Some(replayer) => replayer.get_trajectory().iter().copied().collect(),
+        None => HashSet::new()
+    };
 
     let mut x: usize = mygame.offset.0;
     let mut x_from_zero = 0;
-    for row in &mygame.map {
+    for _row in &mygame.map {
 
-        if x >= mygame.map.len(){
+        if x >= mygame.rows(){
             break
         }
 
@@ -347,19 +452,22 @@ fn draw_map(mygame: &MyGame, canvas: &mut Canvas ){
         let mut y: usize = mygame.offset.1;
         let mut y_from_zero = 0;
 
-        for tile in row{
+        for _tile in &mygame.map[x] {
 
-            if y >= mygame.map.len() {break}
+            if y >= mygame.map[x].len() {break}
             if mygame.size*mygame.zoom*y_from_zero as f32 > SCREEN_SIZE
             {
                 break
             }
 
 
-            let draw_param = DrawParam::new()
+            let mut draw_param = DrawParam::new()
                 .dest(Vec2::new((x - mygame.offset.0) as f32 * &mygame.size * &mygame.zoom,
                                 (y - mygame.offset.1) as f32 * &mygame.size * &mygame.zoom))
                 .scale(Vec2::new(mygame.zoom, mygame.zoom));
+            if trajectory.contains(&(x, y)) {
+                draw_param = draw_param.color(Color::new(1.0, 0.6, 0.6, 1.0));
+            }
 
             canvas.draw(choose_image(&mygame, &mygame.map, (x, y)), draw_param);
 
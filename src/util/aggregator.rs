@@ -0,0 +1,240 @@
+pub mod aggregator {
+    use std::collections::VecDeque;
+    use std::ops::AddAssign;
+
+    // MinerRobot
+    use crate::{MinerRobot, RobotState};
+
+    // robotics lib
+    use robotics_lib::event::events::Event;
+
+    /// Counters of the robotics_lib events
+    #[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+    pub struct EventStats {
+        pub moves: usize,
+        pub energy_consumed: usize,
+        pub energy_recharged: usize,
+        /// Amount of items added to the backpack
+        pub items_added: usize,
+        /// Amount of items removed from the backpack
+        pub items_removed: usize,
+        /// Amount of tiles whose content changed
+        pub content_changes: usize,
+        pub time_changes: usize,
+        pub day_changes: usize
+    }
+
+    /// The events of a tick that happened while the robot was in a certain state
+    #[derive(Debug, Clone, PartialEq)]
+    pub struct TickStats {
+        pub tick: usize,
+        pub state: RobotState,
+        pub stats: EventStats
+    }
+
+    /// Keeps rolling statistics of the events of the last ticks
+    #[derive(Debug, Clone)]
+    pub struct EventAggregator {
+        window: usize,
+        ticks: VecDeque<TickStats>,
+        totals: EventStats
+    }
+
+    impl AddAssign for EventStats {
+        fn add_assign(&mut self, other: Self) {
+            self.moves += other.moves;
+            self.energy_consumed += other.energy_consumed;
+            self.energy_recharged += other.energy_recharged;
+            self.items_added += other.items_added;
+            self.items_removed += other.items_removed;
+            self.content_changes += other.content_changes;
+            self.time_changes += other.time_changes;
+            self.day_changes += other.day_changes;
+        }
+    }
+
+    impl EventStats {
+        /// Adds an event to the counters
+        pub fn add_event(&mut self, event: &Event) {
+            match event {
+                Event::Moved(_, _) => self.moves += 1,
+                Event::EnergyConsumed(energy) => self.energy_consumed += energy,
+                Event::EnergyRecharged(energy) => self.energy_recharged += energy,
+                Event::AddedToBackpack(_, quantity) => self.items_added += quantity,
+                Event::RemovedFromBackpack(_, quantity) => self.items_removed += quantity,
+                Event::TileContentUpdated(_, _) => self.content_changes += 1,
+                Event::TimeChanged(_) => self.time_changes += 1,
+                Event::DayChanged(_) => self.day_changes += 1,
+                _ => {}
+            }
+        }
+    }
+
+    impl EventAggregator {
+        /// Creates an aggregator
+        ///
+        /// # Arguments
+        ///
+        /// * `window` - the amount of ticks that are kept
+        pub fn new(window: usize) -> Self {
+            Self {
+                window,
+                ticks: VecDeque::new(),
+                totals: EventStats::default()
+            }
+        }
+        /// Adds an event to the statistics of the tick
+        ///
+        /// # Arguments
+        ///
+        /// * `tick` - the tick of the event
+        /// * `state` - the state of the robot when the event happened
+        /// * `event` - the event
+        pub fn record(&mut self, tick: usize, state: RobotState, event: &Event) {
+            match self.ticks.back_mut() {
+                Some(last) if last.tick == tick && last.state == state => last.stats.add_event(event),
+                _ => {
+                    let mut stats = EventStats::default();
+                    stats.add_event(event);
+                    self.ticks.push_back(TickStats { tick, state, stats });
+                }
+            }
+            self.totals.add_event(event);
+
+            // dropping the ticks that left the window
+            while self.ticks.front().map_or(false, |first| first.tick + self.window <= tick) {
+                self.ticks.pop_front();
+            }
+        }
+        /// Sums the statistics of the last ticks
+        ///
+        /// # Arguments
+        ///
+        /// * `current_tick` - the current tick
+        /// * `last_ticks` - the amount of ticks to look at, the current one included
+        /// * `state` - if set, only the events that happened in this state are counted
+        ///
+        /// # Returns
+        ///
+        /// The sum of the statistics
+        ///
+        /// # Notes
+        ///
+        /// Only the ticks in the window are kept, so last_ticks can't go past it
+        pub fn get_stats(&self, current_tick: usize, last_ticks: usize, state: Option<RobotState>) -> EventStats {
+            let mut stats = EventStats::default();
+            for tick_stats in self.ticks.iter().rev() {
+                if tick_stats.tick + last_ticks <= current_tick {
+                    break;
+                }
+                if state.map_or(true, |state| state == tick_stats.state) {
+                    stats += tick_stats.stats;
+                }
+            }
+            stats
+        }
+        /// Returns the energy consumed in the last ticks, such as the energy used while paving in the last 10 ticks
+        ///
+        /// # Arguments
+        ///
+        /// * `current_tick` - the current tick
+        /// * `last_ticks` - the amount of ticks to look at, the current one included
+        /// * `state` - if set, only the energy consumed in this state is counted
+        pub fn get_energy_consumed(&self, current_tick: usize, last_ticks: usize, state: Option<RobotState>) -> usize {
+            self.get_stats(current_tick, last_ticks, state).energy_consumed
+        }
+        /// Returns the statistics of every tick in the window
+        pub fn get_ticks(&self) -> &VecDeque<TickStats> {
+            &self.ticks
+        }
+        /// Returns the statistics of the whole run
+        pub fn get_totals(&self) -> EventStats {
+            self.totals
+        }
+    }
+
+    impl MinerRobot {
+        /// Returns the statistics of the last ticks
+        ///
+        /// # Arguments
+        ///
+        /// * `last_ticks` - the amount of ticks to look at, the current one included
+        /// * `state` - if set, only the events that happened in this state are counted
+        pub fn get_recent_stats(&self, last_ticks: usize, state: Option<RobotState>) -> EventStats {
+            self.events.get_stats(self.tick, last_ticks, state)
+        }
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use super::*;
+        use robotics_lib::world::tile::Content;
+
+        fn consumed(aggregator: &mut EventAggregator, tick: usize, state: RobotState, energy: usize) {
+            aggregator.record(tick, state, &Event::EnergyConsumed(energy));
+        }
+
+        #[test]
+        fn events_of_the_same_tick_and_state_are_merged() {
+            let mut aggregator = EventAggregator::new(5);
+            consumed(&mut aggregator, 0, RobotState::Exploring, 10);
+            consumed(&mut aggregator, 0, RobotState::Exploring, 5);
+            aggregator.record(0, RobotState::Exploring, &Event::AddedToBackpack(Content::Rock(0), 2));
+            consumed(&mut aggregator, 0, RobotState::Collecting, 1);
+            assert_eq!(aggregator.get_ticks().len(), 2);
+            assert_eq!(aggregator.get_ticks()[0].stats.energy_consumed, 15);
+            assert_eq!(aggregator.get_ticks()[0].stats.items_added, 2);
+            assert_eq!(aggregator.get_ticks()[1].stats.energy_consumed, 1);
+        }
+
+        #[test]
+        fn ticks_outside_the_window_are_dropped() {
+            let mut aggregator = EventAggregator::new(3);
+            for tick in 0..6 {
+                consumed(&mut aggregator, tick, RobotState::Exploring, 1);
+            }
+            let ticks: Vec<usize> = aggregator.get_ticks().iter().map(|tick_stats| tick_stats.tick).collect();
+            assert_eq!(ticks, vec![3, 4, 5]);
+            // the totals keep the whole run
+            assert_eq!(aggregator.get_totals().energy_consumed, 6);
+        }
+
+        #[test]
+        fn last_ticks_includes_the_current_one() {
+            let mut aggregator = EventAggregator::new(20);
+            for tick in 0..10 {
+                consumed(&mut aggregator, tick, RobotState::Exploring, 1);
+            }
+            assert_eq!(aggregator.get_energy_consumed(9, 1, None), 1);
+            assert_eq!(aggregator.get_energy_consumed(9, 3, None), 3);
+            // nothing happened in the last tick
+            assert_eq!(aggregator.get_energy_consumed(10, 1, None), 0);
+            assert_eq!(aggregator.get_energy_consumed(9, 0, None), 0);
+        }
+
+        #[test]
+        fn the_state_filter_only_counts_that_state() {
+            let mut aggregator = EventAggregator::new(10);
+            consumed(&mut aggregator, 0, RobotState::Exploring, 4);
+            consumed(&mut aggregator, 1, RobotState::Paving, 3);
+            consumed(&mut aggregator, 2, RobotState::Exploring, 2);
+            consumed(&mut aggregator, 2, RobotState::Paving, 1);
+            assert_eq!(aggregator.get_energy_consumed(2, 3, Some(RobotState::Paving)), 4);
+            assert_eq!(aggregator.get_energy_consumed(2, 3, Some(RobotState::Exploring)), 6);
+            assert_eq!(aggregator.get_energy_consumed(2, 3, Some(RobotState::Recharging)), 0);
+            assert_eq!(aggregator.get_energy_consumed(2, 3, None), 10);
+        }
+
+        #[test]
+        fn energy_used_while_paving_in_the_last_10_ticks() {
+            let mut aggregator = EventAggregator::new(50);
+            for tick in 0..30 {
+                let state = if tick % 2 == 0 { RobotState::Paving } else { RobotState::Collecting };
+                consumed(&mut aggregator, tick, state, tick);
+            }
+            // ticks 20 to 29, the paving ones are 20, 22, 24, 26 and 28
+            assert_eq!(aggregator.get_energy_consumed(29, 10, Some(RobotState::Paving)), 120);
+            assert_eq!(aggregator.get_stats(29, 10, Some(RobotState::Paving)).energy_consumed, 120);
+        }
+    }
+}
@@ -1,221 +1,233 @@
 pub mod debug {
     // MinerRobot
-    use crate::{MinerRobot, RobotState};
+    use crate::{ActionKind, KnownMap, MinerError, MinerRobot, Pos, Purpose};
 
     // robotics lib
-    use robotics_lib::interface::{go, put};
     use robotics_lib::interface::Direction;
     use robotics_lib::world::tile::{Content, TileType};
     use robotics_lib::world::World;
-    use robotics_lib::world::tile::Tile;
 
     // tools
+    use bob_lib::tracker::GoalType;
     use OwnerSheeps_Sound_Tool::functions::put_sounds::{play_sound_rock_in_g_h_s_s, play_sound_rock_in_lava, play_sound_rock_in_water};
 
+    // other
+    use log::warn;
+    use serde::{Deserialize, Serialize};
+
+    /// A bridge that the robot wants to build
+    #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+    pub struct BridgeJob {
+        /// The walkable tile of the robot's island where the bridge starts
+        pub start: Pos,
+        /// The walkable tile of the target island where the bridge ends
+        pub end: Pos,
+        /// The amount of rocks needed to build the bridge
+        pub cost: usize
+    }
+
     impl MinerRobot {
-        /// Builds the bridge if the collected rocks are enough and if the target doesn't change with time
+        /// Moves the robot to the starting tile of the bridge and checks that the bridge is still the right one
         ///
         /// # Arguments
         ///
         /// * `world` - the world
+        /// * `job` - the bridge that the robot wants to build
         ///
-        /// # Notes
+        /// # Returns
         ///
-        /// The robot performs a certain amount of iterations to make sure that the target is correct:
-        /// - we move the robot to the starting tile
-        /// - once the robot is on the starting tile we calculate the bridge points one more time:
-        ///     - if they change it means that the starting tile is somewhere else, and we repeat the process
-        ///     - if they stay the same we start building the bridge
-        pub fn pave_bridge(&mut self, world: &mut World) {
-            let (mut target_island_coords, mut robot_island_coords) = self.calculate_bridge_points(world);
-
-            // we want to make sure that the target is the right one, so we iterate n amount of times
-            let mut iterations = 0;
-            let max_iterations = 10;
-
-            while iterations < max_iterations {
-                let (robot_row,robot_col) = self.get_coordinates();
-                // checking the collected rock's amount
-                let rocks_to_build_bridge = self.get_paving_cost(&self.get_map(world), robot_island_coords, target_island_coords);
-                if self.rocks_collected < rocks_to_build_bridge {
-                    break;
-                }
-                // if the robot is not on the starting tile to build the bridge, we move it there
-                if (robot_row as i32, robot_col as i32) != robot_island_coords {
-                    self.move_to_coords(world, &self.get_map(world), robot_island_coords);
-                }
-                let (new_target_island_coords, new_robot_island_coords) = self.calculate_bridge_points(world);
-                if new_target_island_coords == target_island_coords {
-                    self.start_building_bridge(world, target_island_coords);
-                    self.rocks_collected -= rocks_to_build_bridge;
-                    break;
-                } else {
-                    target_island_coords = new_target_island_coords;
-                    robot_island_coords = new_robot_island_coords;
-                }
-                iterations += 1;
-            }
-            // the game ends if there have been too many iterations without finding the correct tile
-            if iterations >= max_iterations {
-                println!("The target keeps on changing {}", max_iterations);
-                self.game_is_over();
-                println!("{:?}", self);
+        /// None if the robot is on the starting tile and the target didn't change, otherwise the new bridge to build.
+        /// A MinerError if the robot cannot move to the starting tile
+        pub fn move_to_bridge_start(&mut self, world: &mut World, job: &BridgeJob) -> Result<Option<BridgeJob>, MinerError> {
+            // if the robot is not on the starting tile to build the bridge, we move it there
+            if self.get_position() != job.start {
+                self.move_to_coords(world, job.start, Purpose::Paving)?;
             }
+            Ok(match self.calculate_bridge_job(&self.known_map) {
+                Some(new_job) if new_job.end == job.end && self.get_position() == job.start => None,
+                Some(new_job) => Some(new_job),
+                None => Some(job.clone())
+            })
         }
-        /// Starts building the bridge and sets the robot's state to PavingBridge
+        /// Builds the bridge from the robot's coordinates and reads the rock count again from the backpack
         ///
         /// # Arguments
         ///
         /// * `world` - the world
-        /// * `(target_island_row,target_island_col)` - the target island's coordinates
-        fn start_building_bridge(&mut self, world: &mut World, (target_island_row,target_island_col): (i32, i32)) {
-            self.state = RobotState::PavingBridge;
-            self.build_along_row_and_col(world, target_island_row, target_island_col);
+        /// * `job` - the bridge to build
+        ///
+        /// # Returns
+        ///
+        /// A MinerError if a put or a move fails while paving
+        ///
+        /// # Notes
+        ///
+        /// The bridge is built from the robot's coordinates, so a bridge that got interrupted can be resumed later on
+        pub fn build_bridge(&mut self, world: &mut World, job: &BridgeJob) -> Result<(), MinerError> {
+            self.build_along_row_and_col(world, job.end)?;
+            // the rocks put can be less than the estimated cost, so the count is read again from the backpack
+            self.sync_rock_count();
+            if !self.waiting_for_energy {
+                self.bridges_built += 1;
+            }
+            Ok(())
+        }
+        /// Calculates the bridge that would connect the robot's island to the closest island
+        ///
+        /// # Arguments
+        ///
+        /// * `map` - the known world
+        ///
+        /// # Returns
+        ///
+        /// An option of BridgeJob, None if there is no island to connect
+        pub fn calculate_bridge_job(&self, map: &KnownMap) -> Option<BridgeJob> {
+            let (end, start) = self.calculate_bridge_points(map)?;
+            Some(BridgeJob {
+                start,
+                end,
+                cost: self.get_paving_cost(map, start, end)
+            })
         }
         /// Calculates the coordinates that will be connected by the bridge
         ///
         /// # Arguments
         ///
-        /// * `world` - the world
+        /// * `map` - the known world
         ///
         /// # Returns
         ///
-        /// A tuple of coordinates indicating the two coordinates that will be at the start and at the end of the bridge
-        fn calculate_bridge_points(&mut self, world: &World) -> ((i32,i32),(i32,i32)) {
-            let mut islands = self.get_islands(&self.get_map(world));
-            let discovered_tiles = self.get_map(world);
+        /// An option of a tuple of coordinates indicating the two coordinates that will be at the end and at the start of the bridge
+        fn calculate_bridge_points(&self, map: &KnownMap) -> Option<(Pos, Pos)> {
+            let mut islands = self.get_islands(map);
 
             // getting both the robot's island and the target island
-            let robot_island = self.get_robot_island(&islands).unwrap_or_else(|| vec![]);
-            let target_island = self.get_closest_island_to_robot(&mut islands).unwrap_or_else(|| vec![(0, 0)]);
+            let robot_island = self.get_robot_island(&islands)?;
+            let target_island = self.get_closest_island_to_robot(&mut islands)?;
 
-            self.get_closest_points(&discovered_tiles, robot_island, target_island).unwrap_or_else(|| ((0, 0), (0, 0)))
+            self.get_closest_points(map, robot_island, target_island)
         }
         /// Calls the method to build a bridge on both rows and columns
         ///
         /// # Arguments
         ///
         /// * `world` - the world
-        /// * `map` - the known world
-        /// * `row` - the target's row
-        /// * `col` - the target's column
+        /// * `target` - the target's coordinates
         ///
         /// # Notes:
         ///
         /// Given the distance between the robot's row coordinate and the target's it calls the build_to_direction() method.
         /// The same goes for the column.
-        fn build_along_row_and_col(&mut self, world: &mut World, row: i32, col: i32) {
-            let (robot_row,robot_col) = self.get_coordinates();
-            let row_distance = robot_row as i32 - row;
-            let col_distance = robot_col as i32 - col;
+        fn build_along_row_and_col(&mut self, world: &mut World, target: Pos) -> Result<(), MinerError> {
+            let robot = self.get_position();
+            let row_distance = robot.row.abs_diff(target.row);
+            let col_distance = robot.col.abs_diff(target.col);
 
             // building following rows
-            if row_distance < 0 {
-                self.build_to_direction(world,row_distance,&Direction::Down);
-            } else if row_distance > 0 {
-                self.build_to_direction(world,row_distance,&Direction::Up);
+            if robot.row < target.row {
+                self.build_to_direction(world,row_distance,&Direction::Down)?;
+            } else if robot.row > target.row {
+                self.build_to_direction(world,row_distance,&Direction::Up)?;
             }
             // building following columns
-            if col_distance < 0 {
-                self.build_to_direction(world,col_distance,&Direction::Right);
-            } else if col_distance > 0 {
-                self.build_to_direction(world,col_distance,&Direction::Left);
+            if robot.col < target.col {
+                self.build_to_direction(world,col_distance,&Direction::Right)?;
+            } else if robot.col > target.col {
+                self.build_to_direction(world,col_distance,&Direction::Left)?;
             }
             if row_distance == 0 && col_distance == 0 {
-                println!("Cannot build since the robot is already on the target tile");
+                warn!(target: "bridge", "Cannot build since the robot is already on the target tile");
             }
+            Ok(())
         }
         /// Builds a bridge given a direction and a distance
         ///
         /// # Arguments
         ///
         /// * `world` - the world
-        /// * `map` - the known world
         /// * `distance` - the amount of blocks that are getting paved
         /// * `direction` - the direction that the robot will pave on
-        fn build_to_direction(&mut self, world: &mut World, distance: i32, direction: &Direction) {
-            let mut distance_left = distance.abs();
+        ///
+        /// # Returns
+        ///
+        /// A MinerError if a put or a move fails, the error holds the tile the robot was paving.
+        /// A MinerError if the bridge goes outside the known map
+        fn build_to_direction(&mut self, world: &mut World, distance: usize, direction: &Direction) -> Result<(), MinerError> {
+            let mut distance_left = distance;
 
             // iterating through all the tiles that need to connect the robot to the target except for the last one which is the target tile
             while distance_left > 1 {
-                let map = self.get_map(world);
-                let (mut row,mut col) = self.get_coordinates();
-
-                // calculating the offset in order to find the next tile's coordinates
-                let (offset_row,offset_col) = self.direction_to_offset(&direction);
-                row = ((row as i32) + offset_row) as usize;
-                col = ((col as i32) + offset_col) as usize;
+                // finding the next tile, which must be inside the known map
+                let next = match self.known_map.get_tiles().step(self.get_position(), direction) {
+                    Some(next) => next,
+                    None => return Err(MinerError::Planning {
+                        tile: Some(self.get_coordinates()),
+                        reason: String::from("the bridge goes outside the known map")
+                    })
+                };
+                let tile: (usize, usize) = next.into();
 
                 // calculating the amount of rocks needed to build the bridge
-                let quantity = self.get_tile_cost(&map[row][col].tile_type);
+                let tile_type = match self.known_map.get_tiles().get(next) {
+                    Some(next_tile) => next_tile.tile_type.clone(),
+                    None => break
+                };
+                let quantity = self.get_tile_cost(&tile_type);
 
-                // calling put to pave the bridge if the coordinates are within bounds and the tile is not walkable
-                let error = if self.is_in_bounds(&map,row as i32,col as i32) && !self.is_walkable(&map[row][col].tile_type) {
-                    put(self, world, Content::Rock(0), quantity, direction.clone())
+                // calling put to pave the bridge if the tile is not walkable
+                let error = if !self.is_walkable(&tile_type) {
+                    self.metered_put(world, Content::Rock(0), quantity, direction.clone(), Purpose::Paving)
                 } else {
                     Ok(0)
                 };
-                let _ = match error {
-                    Ok(_) => {
-                        self.play_sound_paving(&map[row][col].tile_type);
-                        self.manage_energy(world);
-                        // in case of error a message is returned
-                        let msg = format!("Failed to move {:?}", direction);
-                        go(self,world,direction.clone()).expect(msg.as_str());
-                        Ok(())
+                match error {
+                    Ok(quantity) => {
+                        self.update_goal(GoalType::PutItems, Some(Content::Rock(0)), quantity);
+                        self.play_sound_paving(&tile_type);
+                        if !self.manage_energy(world) {
+                            return Ok(());
+                        }
+                        if let Err(e) = self.metered_go(world, direction.clone(), Purpose::Paving) {
+                            return Err(self.lib_failure(world, ActionKind::Go, tile, e));
+                        }
                     },
                     Err(e) => {
-                        self.catch_lib_error(world, e);
-                        Err(())
+                        return Err(self.lib_failure(world, ActionKind::Put, tile, e));
                     }
-                };
+                }
 
                 distance_left -= 1;
             }
+            Ok(())
         }
         /// Calculates the total cost of building a bridge from the robot's coordinates to the given ones
         ///
         /// # Arguments
         ///
         /// * `map` - the known map
-        /// * `(robot_row,robot_col)` - the robot's coordinates
-        /// * `(island_row, island_col)` - the target's coordinates
+        /// * `start` - the robot's coordinates
+        /// * `end` - the target's coordinates
         ///
         /// # Returns
         ///
         /// The cost of building a bridge from the robot's coordinates to the target's
-        pub fn get_paving_cost(&self, map: &Vec<Vec<Tile>>, (robot_row,robot_col): (i32,i32), (island_row, island_col): (i32, i32)) -> usize {
+        pub fn get_paving_cost(&self, map: &KnownMap, start: Pos, end: Pos) -> usize {
             // initializing the total cost variable and the tmp_cost (indicates the last evaluated cost)
             let mut cost = 0;
             let mut curr_cost = 0;
 
-            let (mut curr_row, mut curr_col) = (robot_row,robot_col);
+            let mut curr = start;
+            let tiles = map.get_tiles();
 
             // loop through all the coordinates that separate the current coordinates to the target's
-            // since the robot builds first along rows and then along columns, we start calculating the rows first
-            while curr_row != island_row {
-                let next_row = if (curr_row) < island_row {
-                    curr_row + 1
-                } else if (curr_row) > island_row {
-                    curr_row - 1
-                } else {
-                    curr_row
-                };
-                curr_cost = self.get_tile_cost(&map[next_row as usize][curr_col as usize].tile_type);
-                cost += curr_cost;
-                curr_row = next_row;
-            }
-            while curr_col != island_col {
-                let next_col = if (curr_col) < island_col {
-                    curr_col + 1
-                } else if (curr_col) > island_col {
-                    curr_col - 1
-                } else {
-                    curr_col
+            // since the robot builds first along rows and then along columns, the direction covers the rows first
+            while let Some(direction) = curr.direction_towards(end) {
+                curr = match tiles.step(curr, &direction) {
+                    Some(next) => next,
+                    None => break
                 };
-                curr_cost = self.get_tile_cost(&map[curr_row as usize][next_col as usize].tile_type);
+                curr_cost = tiles.get(curr).map_or(0, |tile| self.get_tile_cost(&tile.tile_type));
                 cost += curr_cost;
-                curr_col = next_col;
             }
             // subtracting the curr_cost since it is the cost of the last tile which is the target tile
             // and the robot doesn't need to build a bridge there
@@ -0,0 +1,204 @@
+pub mod builder {
+    use std::collections::HashSet;
+    use std::sync::{Arc, Mutex};
+
+    // MinerRobot
+    use crate::{ConfigError, Coverage, DefaultBridgeStrategy, KnownMap, EnergyLedger, EventAggregator, GoalCallback, GoalEvent, MinerConfig, MinerRobot,
+                MinerStrategy, RechargeStrategy, Recorder, RobotSnapshot, RobotState, Watchdog};
+
+    // robotics lib
+    use robotics_lib::runner::Robot;
+
+    // tools
+    use bob_lib::tracker::GoalTracker;
+    use sense_and_find_by_rustafariani::Lssf;
+
+    // other
+    use log::warn;
+
+    // name of the robot when none is given
+    const DEFAULT_NAME: &str = "The default miner";
+
+    /// Builds a MinerRobot, every option that is not set keeps its default value
+    pub struct MinerRobotBuilder {
+        name: String,
+        config: MinerConfig,
+        robot: Option<Robot>,
+        recharge_strategy: Option<Box<dyn RechargeStrategy>>,
+        strategy: Option<Box<dyn MinerStrategy>>,
+        recorder: Option<Recorder>,
+        record_file: Option<String>,
+        game_over: Option<Arc<Mutex<bool>>>,
+        coordinates: Option<Arc<Mutex<(usize, usize)>>>,
+        snapshot: Option<Arc<Mutex<RobotSnapshot>>>,
+        goal_callbacks: Vec<GoalCallback>
+    }
+
+    impl Default for MinerRobotBuilder {
+        fn default() -> Self {
+            Self::new()
+        }
+    }
+
+    impl MinerRobotBuilder {
+        /// Creates a builder with the default options
+        pub fn new() -> Self {
+            Self {
+                name: String::from(DEFAULT_NAME),
+                config: MinerConfig::default(),
+                robot: None,
+                recharge_strategy: None,
+                strategy: None,
+                recorder: None,
+                record_file: None,
+                game_over: None,
+                coordinates: None,
+                snapshot: None,
+                goal_callbacks: Vec::new()
+            }
+        }
+        /// Sets the name of the robot
+        pub fn name(mut self, name: impl Into<String>) -> Self {
+            self.name = name.into();
+            self
+        }
+        /// Sets the configuration of the run
+        pub fn config(mut self, config: MinerConfig) -> Self {
+            self.config = config;
+            self
+        }
+        /// Sets the robotics_lib Robot the miner starts from
+        pub fn robot(mut self, robot: Robot) -> Self {
+            self.robot = Some(robot);
+            self
+        }
+        /// Sets the recharge strategy, by default it is chosen by the fair-play option of the configuration
+        pub fn recharge_strategy(mut self, strategy: impl RechargeStrategy + 'static) -> Self {
+            self.recharge_strategy = Some(Box::new(strategy));
+            self
+        }
+        /// Sets the strategy that decides what the robot does every tick, by default it is the DefaultBridgeStrategy
+        pub fn strategy(mut self, strategy: impl MinerStrategy + 'static) -> Self {
+            self.strategy = Some(Box::new(strategy));
+            self
+        }
+        /// Records every tick of the robot into the given file, overriding the record_file of the configuration
+        ///
+        /// # Notes
+        ///
+        /// The file is created when the robot is built, after the configuration has been set
+        pub fn record_file(mut self, path: impl Into<String>) -> Self {
+            self.record_file = Some(path.into());
+            self
+        }
+        /// Records every tick of the robot, by default the record_file of the configuration is used
+        pub fn recorder(mut self, recorder: Recorder) -> Self {
+            self.recorder = Some(recorder);
+            self
+        }
+        /// Shares the game over flag with another thread
+        pub fn game_over(mut self, game_over: Arc<Mutex<bool>>) -> Self {
+            self.game_over = Some(game_over);
+            self
+        }
+        /// Shares the robot's coordinates with another thread
+        pub fn coordinates(mut self, coordinates: Arc<Mutex<(usize, usize)>>) -> Self {
+            self.coordinates = Some(coordinates);
+            self
+        }
+        /// Shares the snapshot published after every tick with another thread
+        pub fn snapshot(mut self, snapshot: Arc<Mutex<RobotSnapshot>>) -> Self {
+            self.snapshot = Some(snapshot);
+            self
+        }
+        /// Registers a callback that gets called for every goal event
+        pub fn on_goal_event(mut self, callback: impl FnMut(&GoalEvent) + Send + 'static) -> Self {
+            self.goal_callbacks.push(Box::new(callback));
+            self
+        }
+        /// Validates the options and builds the robot
+        ///
+        /// # Returns
+        ///
+        /// The MinerRobot, or a ConfigError if the name is empty, if the configuration is not valid,
+        /// or if the recharge strategy is not allowed by the fair-play feature.
+        /// A recording that can't be created is skipped with a warning
+        pub fn build(self) -> Result<MinerRobot, ConfigError> {
+            if self.name.trim().is_empty() {
+                return Err(ConfigError::Invalid(String::from("the name of the robot cannot be empty")));
+            }
+            self.config.validate()?;
+
+            let mut config = self.config;
+            if let Some(path) = self.record_file {
+                config.record_file = Some(path);
+            }
+            let recorder = match (self.recorder, &config.record_file) {
+                (Some(recorder), _) => Some(recorder),
+                // the run goes on without a recording rather than not starting at all
+                (None, Some(path)) => match Recorder::create(path) {
+                    Ok(recorder) => Some(recorder),
+                    Err(e) => {
+                        warn!(target: "state", "Cannot create the recording {}, the run is not recorded: {}", path, e);
+                        None
+                    }
+                },
+                (None, None) => None
+            };
+            let recharge_strategy = match self.recharge_strategy {
+                Some(strategy) if cfg!(feature = "fair-play") && !strategy.is_fair_play() => {
+                    return Err(ConfigError::Invalid(format!("the {} recharge cannot be used with the fair-play feature", strategy.name())));
+                },
+                Some(strategy) => strategy,
+                None => MinerRobot::default_recharge_strategy(config.fair_play)
+            };
+
+            Ok(MinerRobot {
+                robot: self.robot.unwrap_or_else(Robot::new),
+                name: self.name,
+                goal_tracker: GoalTracker::new(),
+                rocks_collected: 0,
+                rocks_mined: 0,
+                bridges_built: 0,
+                scan_distance: config.scan_distance,
+                lssf: Lssf::new(),
+                world_scanned: false,
+                spyglass: None,
+                scan_budget: config.energy_budget,
+                scan_threshold: config.threshold,
+                scan_reports: Vec::new(),
+                explored_frontiers: HashSet::new(),
+                explored_frontiers_version: None,
+                coverage: Coverage::default(),
+                known_map: KnownMap::default(),
+                lssf_map_version: None,
+                energy_ledger: EnergyLedger::default(),
+                events: EventAggregator::new(config.event_window),
+                state: RobotState::Exploring,
+                state_history: Vec::new(),
+                resource_target: None,
+                skipped_targets: HashSet::new(),
+                bridge_job: None,
+                current_path: Vec::new(),
+                watchdog: Watchdog::new(config.watchdog_window),
+                bridge_iterations: 0,
+                resume_state: None,
+                waiting_for_energy: false,
+                recharge_strategy: Some(recharge_strategy),
+                recorder,
+                strategy: Some(self.strategy.unwrap_or_else(|| Box::new(DefaultBridgeStrategy))),
+                game_over: self.game_over.unwrap_or_else(|| Arc::new(Mutex::from(false))),
+                game_over_reason: None,
+                run_summary: None,
+                coordinates: self.coordinates.unwrap_or_else(|| Arc::new(Mutex::new((0,0)))),
+                snapshot: self.snapshot.unwrap_or_default(),
+                tick: 0,
+                goal_records: Vec::new(),
+                goals_completed: 0,
+                goal_callbacks: self.goal_callbacks,
+                goal_senders: Vec::new(),
+                config
+            })
+        }
+    }
+}
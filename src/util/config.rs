@@ -0,0 +1,450 @@
+pub mod config {
+    use std::env;
+    use std::fmt::{Display, Formatter};
+    use std::fs;
+    use std::path::{Path, PathBuf};
+    use std::str::FromStr;
+
+    // MinerRobot
+    use crate::{DIRECTION, ENERGY_BUDGET, EVENT_WINDOW, GOAL_QUANTITY, GOAL_TIMEOUT, LOG_LEVEL, MAX_BRIDGE_ITERATIONS, MAX_RECOVERIES, CONTENT_CANDIDATES, MAX_THRESHOLD,
+                MIN_DISCOVERY_BUDGET, MIN_ENERGY, MIN_THRESHOLD, RANGE, RESUME_ENERGY, SCAN_DISTANCE,
+                THRESHOLD, WATCHDOG_WINDOW};
+
+    // robotics lib
+    use robotics_lib::interface::Direction;
+
+    // other
+    use log::LevelFilter;
+    use serde::{Deserialize, Serialize};
+
+    // prefix of the environment variables that override the configuration
+    const ENV_PREFIX: &str = "HOLY_CRAB_";
+
+    /// Everything that can be tuned in a run of the MinerRobot
+    ///
+    /// # Notes
+    ///
+    /// The fields that are missing from a file keep their default value
+    #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+    #[serde(default)]
+    pub struct MinerConfig {
+        /// The quantity of rocks of every goal
+        pub goal_quantity: u32,
+        /// Amount of ticks after which a goal that has not been completed expires
+        pub goal_timeout: usize,
+        /// Energy budget of the spyglass, the budget of each scan is adapted from it
+        pub energy_budget: usize,
+        /// View threshold of the spyglass, the threshold of each scan is adapted from it
+        pub threshold: f64,
+        /// The energy budget of a scan never goes below this value, unless the robot has less energy
+        pub min_discovery_budget: usize,
+        /// Lowest view threshold that a scan can use
+        pub min_threshold: f64,
+        /// Highest view threshold that a scan can use
+        pub max_threshold: f64,
+        /// Distance of the first scan
+        pub scan_distance: usize,
+        /// The robot's energy cannot get lower than this value
+        pub min_energy: usize,
+        /// When waiting for energy the robot goes on once its energy gets back to this value
+        pub resume_energy: usize,
+        /// Range of the CollectAll tool
+        pub range: usize,
+        /// Direction of the road paving machine: up, down, left or right
+        pub direction: String,
+        /// Amount of closest tiles whose path cost is computed when the robot looks for content
+        pub content_candidates: usize,
+        /// Amount of times the robot checks the bridge points before giving up
+        pub max_bridge_iterations: usize,
+        /// Disables the Dynamo refills, it cannot be false when the fair-play feature is enabled
+        pub fair_play: bool,
+        /// Amount of ticks without progress after which the robot is stuck
+        pub watchdog_window: usize,
+        /// Amount of recoveries without progress before the run ends
+        pub max_recoveries: usize,
+        /// Amount of ticks of events kept for the statistics
+        pub event_window: usize,
+        /// Most verbose level that gets logged: off, error, warn, info, debug or trace
+        pub log_level: String,
+        /// File where the log gets written as JSON lines
+        pub log_file: Option<String>,
+        /// Whether the log is also written to the terminal
+        pub log_to_terminal: bool,
+        /// File where every tick gets recorded, such as world/bridge2.jsonl
+        pub record_file: Option<String>
+    }
+
+    /// Errors that can happen while loading the configuration
+    #[derive(Debug)]
+    pub enum ConfigError {
+        Io(std::io::Error),
+        Parse(String),
+        Invalid(String)
+    }
+
+    impl Display for ConfigError {
+        fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+            match self {
+                ConfigError::Io(e) => write!(f, "Cannot read the configuration: {}", e),
+                ConfigError::Parse(e) => write!(f, "Cannot parse the configuration: {}", e),
+                ConfigError::Invalid(e) => write!(f, "Invalid configuration: {}", e)
+            }
+        }
+    }
+
+    impl std::error::Error for ConfigError {}
+
+    impl Default for MinerConfig {
+        fn default() -> Self {
+            Self {
+                goal_quantity: GOAL_QUANTITY,
+                goal_timeout: GOAL_TIMEOUT,
+                energy_budget: ENERGY_BUDGET,
+                threshold: THRESHOLD,
+                min_discovery_budget: MIN_DISCOVERY_BUDGET,
+                min_threshold: MIN_THRESHOLD,
+                max_threshold: MAX_THRESHOLD,
+                scan_distance: SCAN_DISTANCE,
+                min_energy: MIN_ENERGY,
+                resume_energy: RESUME_ENERGY,
+                range: RANGE,
+                direction: String::from(DIRECTION),
+                content_candidates: CONTENT_CANDIDATES,
+                max_bridge_iterations: MAX_BRIDGE_ITERATIONS,
+                fair_play: cfg!(feature = "fair-play"),
+                watchdog_window: WATCHDOG_WINDOW,
+                max_recoveries: MAX_RECOVERIES,
+                event_window: EVENT_WINDOW,
+                log_level: String::from(LOG_LEVEL),
+                log_file: None,
+                log_to_terminal: true,
+                record_file: None
+            }
+        }
+    }
+
+    impl MinerConfig {
+        /// Loads the configuration, the environment variables override the values of the file
+        ///
+        /// # Arguments
+        ///
+        /// * `path` - the TOML or JSON file, if None the HOLY_CRAB_CONFIG variable is used and if it is missing the defaults
+        ///
+        /// # Returns
+        ///
+        /// The validated configuration
+        pub fn load(path: Option<&Path>) -> Result<Self, ConfigError> {
+            let env_path = env::var(format!("{}CONFIG", ENV_PREFIX)).ok();
+            let mut config = match path.map(Path::to_path_buf).or(env_path.map(PathBuf::from)) {
+                Some(path) => Self::parse_file(path)?,
+                None => Self::default()
+            };
+            // the file is validated together with the overrides, so a variable can fix a value of the file
+            config.apply_env_overrides()?;
+            config.validate()?;
+            Ok(config)
+        }
+        /// Reads the configuration from a file, the format is chosen by the extension
+        ///
+        /// # Arguments
+        ///
+        /// * `path` - the path of a .json or .toml file
+        ///
+        /// # Returns
+        ///
+        /// The validated configuration
+        pub fn from_file(path: impl AsRef<Path>) -> Result<Self, ConfigError> {
+            let config = Self::parse_file(path)?;
+            config.validate()?;
+            Ok(config)
+        }
+        /// Parses a TOML configuration
+        ///
+        /// # Returns
+        ///
+        /// The validated configuration
+        pub fn from_toml_str(text: &str) -> Result<Self, ConfigError> {
+            let config = parse_toml(text)?;
+            config.validate()?;
+            Ok(config)
+        }
+        /// Parses a JSON configuration
+        ///
+        /// # Returns
+        ///
+        /// The validated configuration
+        pub fn from_json_str(text: &str) -> Result<Self, ConfigError> {
+            let config = parse_json(text)?;
+            config.validate()?;
+            Ok(config)
+        }
+        /// Reads a file without validating it, the format is chosen by the extension
+        fn parse_file(path: impl AsRef<Path>) -> Result<Self, ConfigError> {
+            let path = path.as_ref();
+            let text = fs::read_to_string(path).map_err(ConfigError::Io)?;
+            match path.extension().and_then(|e| e.to_str()) {
+                Some("json") => parse_json(&text),
+                _ => parse_toml(&text)
+            }
+        }
+        /// Overrides the fields that have an environment variable, such as HOLY_CRAB_GOAL_QUANTITY
+        pub fn apply_env_overrides(&mut self) -> Result<(), ConfigError> {
+            self.apply_overrides(|variable| env::var(variable).ok())
+        }
+        /// Overrides the fields whose variable is found by the lookup
+        ///
+        /// # Arguments
+        ///
+        /// * `lookup` - returns the value of a variable, such as HOLY_CRAB_GOAL_QUANTITY, if it is set
+        fn apply_overrides(&mut self, lookup: impl Fn(&str) -> Option<String>) -> Result<(), ConfigError> {
+            override_from(&lookup, "GOAL_QUANTITY", &mut self.goal_quantity)?;
+            override_from(&lookup, "GOAL_TIMEOUT", &mut self.goal_timeout)?;
+            override_from(&lookup, "ENERGY_BUDGET", &mut self.energy_budget)?;
+            override_from(&lookup, "THRESHOLD", &mut self.threshold)?;
+            override_from(&lookup, "MIN_DISCOVERY_BUDGET", &mut self.min_discovery_budget)?;
+            override_from(&lookup, "MIN_THRESHOLD", &mut self.min_threshold)?;
+            override_from(&lookup, "MAX_THRESHOLD", &mut self.max_threshold)?;
+            override_from(&lookup, "SCAN_DISTANCE", &mut self.scan_distance)?;
+            override_from(&lookup, "MIN_ENERGY", &mut self.min_energy)?;
+            override_from(&lookup, "RESUME_ENERGY", &mut self.resume_energy)?;
+            override_from(&lookup, "RANGE", &mut self.range)?;
+            override_from(&lookup, "DIRECTION", &mut self.direction)?;
+            override_from(&lookup, "CONTENT_CANDIDATES", &mut self.content_candidates)?;
+            override_from(&lookup, "MAX_BRIDGE_ITERATIONS", &mut self.max_bridge_iterations)?;
+            override_from(&lookup, "FAIR_PLAY", &mut self.fair_play)?;
+            override_from(&lookup, "WATCHDOG_WINDOW", &mut self.watchdog_window)?;
+            override_from(&lookup, "MAX_RECOVERIES", &mut self.max_recoveries)?;
+            override_from(&lookup, "EVENT_WINDOW", &mut self.event_window)?;
+            override_from(&lookup, "LOG_LEVEL", &mut self.log_level)?;
+            if let Some(path) = lookup(&format!("{}LOG_FILE", ENV_PREFIX)) {
+                self.log_file = Some(path);
+            }
+            override_from(&lookup, "LOG_TO_TERMINAL", &mut self.log_to_terminal)?;
+            if let Some(path) = lookup(&format!("{}RECORD_FILE", ENV_PREFIX)) {
+                self.record_file = Some(path);
+            }
+            Ok(())
+        }
+        /// Checks that the values can be used by the robot
+        pub fn validate(&self) -> Result<(), ConfigError> {
+            let invalid = |message: &str| Err(ConfigError::Invalid(String::from(message)));
+
+            if self.goal_quantity == 0 {
+                return invalid("goal_quantity must be greater than 0");
+            }
+            if self.goal_timeout == 0 {
+                return invalid("goal_timeout must be greater than 0");
+            }
+            if self.scan_distance == 0 {
+                return invalid("scan_distance must be greater than 0");
+            }
+            if !(0.0..=1.0).contains(&self.min_threshold) || !(0.0..=1.0).contains(&self.max_threshold) || self.min_threshold > self.max_threshold {
+                return invalid("min_threshold and max_threshold must be between 0 and 1, with min_threshold <= max_threshold");
+            }
+            if self.threshold < self.min_threshold || self.threshold > self.max_threshold {
+                return invalid("threshold must be between min_threshold and max_threshold");
+            }
+            if self.energy_budget < self.min_discovery_budget {
+                return invalid("energy_budget must be at least min_discovery_budget");
+            }
+            if self.resume_energy < self.min_energy {
+                return invalid("resume_energy must be at least min_energy");
+            }
+            if self.content_candidates == 0 {
+                return invalid("content_candidates must be greater than 0");
+            }
+            if cfg!(feature = "fair-play") && !self.fair_play {
+                return invalid("fair_play cannot be disabled when the fair-play feature is enabled");
+            }
+            if self.max_bridge_iterations == 0 {
+                return invalid("max_bridge_iterations must be greater than 0");
+            }
+            if parse_direction(&self.direction).is_none() {
+                return invalid("direction must be one of up, down, left or right");
+            }
+            if self.watchdog_window < 2 {
+                return invalid("watchdog_window must be at least 2");
+            }
+            if self.event_window == 0 {
+                return invalid("event_window must be greater than 0");
+            }
+            if self.log_level.parse::<LevelFilter>().is_err() {
+                return invalid("log_level must be one of off, error, warn, info, debug or trace");
+            }
+            Ok(())
+        }
+        /// Returns the direction of the road paving machine
+        pub fn get_direction(&self) -> Direction {
+            parse_direction(&self.direction).unwrap_or(Direction::Up)
+        }
+        /// Returns the most verbose level of the logger
+        pub fn get_log_level(&self) -> LevelFilter {
+            self.log_level.parse().unwrap_or(LevelFilter::Info)
+        }
+    }
+
+    /// Parses a TOML configuration without validating it
+    fn parse_toml(text: &str) -> Result<MinerConfig, ConfigError> {
+        toml::from_str(text).map_err(|e| ConfigError::Parse(e.to_string()))
+    }
+
+    /// Parses a JSON configuration without validating it
+    fn parse_json(text: &str) -> Result<MinerConfig, ConfigError> {
+        serde_json::from_str(text).map_err(|e| ConfigError::Parse(e.to_string()))
+    }
+
+    /// Converts a string into a direction
+    ///
+    /// # Arguments
+    ///
+    /// * `direction` - up, down, left or right, in any case
+    ///
+    /// # Returns
+    ///
+    /// An option of Direction
+    fn parse_direction(direction: &str) -> Option<Direction> {
+        match direction.to_lowercase().as_str() {
+            "up" => Some(Direction::Up),
+            "down" => Some(Direction::Down),
+            "left" => Some(Direction::Left),
+            "right" => Some(Direction::Right),
+            _ => None
+        }
+    }
+
+    /// Replaces the value with the one of the variable, if it exists
+    ///
+    /// # Arguments
+    ///
+    /// * `lookup` - returns the value of a variable, if it is set
+    /// * `name` - the name of the variable, without the prefix
+    /// * `value` - the value to override
+    fn override_from<T: FromStr>(lookup: &impl Fn(&str) -> Option<String>, name: &str, value: &mut T) -> Result<(), ConfigError> {
+        let variable = format!("{}{}", ENV_PREFIX, name);
+        if let Some(text) = lookup(&variable) {
+            *value = text.trim().parse().map_err(|_| ConfigError::Parse(format!("{} has an invalid value: {}", variable, text)))?;
+        }
+        Ok(())
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use super::*;
+        use std::collections::HashMap;
+
+        fn lookup(variables: &[(&str, &str)]) -> impl Fn(&str) -> Option<String> {
+            let variables: HashMap<String, String> = variables.iter().map(|(k, v)| (k.to_string(), v.to_string())).collect();
+            move |variable| variables.get(variable).cloned()
+        }
+
+        fn assert_invalid(change: impl FnOnce(&mut MinerConfig), field: &str) {
+            let mut config = MinerConfig::default();
+            change(&mut config);
+            match config.validate() {
+                Err(ConfigError::Invalid(message)) => assert!(message.contains(field), "{}: {}", field, message),
+                other => panic!("{} should be invalid, got {:?}", field, other)
+            }
+        }
+
+        #[test]
+        fn default_is_valid() {
+            assert!(MinerConfig::default().validate().is_ok());
+        }
+
+        #[test]
+        fn parses_toml_and_keeps_the_missing_fields() {
+            let config = MinerConfig::from_toml_str("goal_quantity = 8\ndirection = \"left\"\nlog_file = \"run.jsonl\"\n").unwrap();
+            assert_eq!(config.goal_quantity, 8);
+            assert_eq!(config.direction, "left");
+            assert_eq!(config.log_file, Some(String::from("run.jsonl")));
+            assert!(matches!(config.get_direction(), Direction::Left));
+
+            let defaults = MinerConfig::default();
+            assert_eq!(config.scan_distance, defaults.scan_distance);
+            assert_eq!(config.energy_budget, defaults.energy_budget);
+            assert_eq!(config.record_file, None);
+        }
+
+        #[test]
+        fn parses_json_and_keeps_the_missing_fields() {
+            let config = MinerConfig::from_json_str(r#"{"min_energy": 50, "resume_energy": 80, "log_level": "debug"}"#).unwrap();
+            assert_eq!(config.min_energy, 50);
+            assert_eq!(config.resume_energy, 80);
+            assert_eq!(config.get_log_level(), LevelFilter::Debug);
+            assert_eq!(config.range, MinerConfig::default().range);
+
+            assert_eq!(MinerConfig::from_json_str("{}").unwrap(), MinerConfig::default());
+        }
+
+        #[test]
+        fn rejects_malformed_and_invalid_text() {
+            assert!(matches!(MinerConfig::from_toml_str("goal_quantity = \"many\""), Err(ConfigError::Parse(_))));
+            assert!(matches!(MinerConfig::from_json_str("{"), Err(ConfigError::Parse(_))));
+            assert!(matches!(MinerConfig::from_toml_str("goal_quantity = 0"), Err(ConfigError::Invalid(_))));
+            assert!(matches!(MinerConfig::from_json_str(r#"{"direction": "north"}"#), Err(ConfigError::Invalid(_))));
+        }
+
+        #[test]
+        fn overrides_win_over_the_file() {
+            let mut config = parse_toml("goal_quantity = 8\nscan_distance = 4\n").unwrap();
+            config.apply_overrides(lookup(&[
+                ("HOLY_CRAB_GOAL_QUANTITY", "12"),
+                ("HOLY_CRAB_DIRECTION", "right"),
+                ("HOLY_CRAB_RECORD_FILE", "world/run.jsonl")
+            ])).unwrap();
+            assert_eq!(config.goal_quantity, 12);
+            assert_eq!(config.scan_distance, 4);
+            assert_eq!(config.direction, "right");
+            assert_eq!(config.record_file, Some(String::from("world/run.jsonl")));
+        }
+
+        #[test]
+        fn overrides_can_fix_the_file() {
+            // the file alone is invalid, the override makes it valid before the validation
+            let mut config = parse_toml("goal_quantity = 0").unwrap();
+            assert!(config.validate().is_err());
+            config.apply_overrides(lookup(&[("HOLY_CRAB_GOAL_QUANTITY", "3")])).unwrap();
+            assert!(config.validate().is_ok());
+        }
+
+        #[test]
+        fn rejects_an_override_that_does_not_parse() {
+            let mut config = MinerConfig::default();
+            let result = config.apply_overrides(lookup(&[("HOLY_CRAB_MIN_ENERGY", "lots")]));
+            assert!(matches!(result, Err(ConfigError::Parse(message)) if message.contains("HOLY_CRAB_MIN_ENERGY")));
+        }
+
+        #[test]
+        fn validates_every_rule() {
+            assert_invalid(|c| c.goal_quantity = 0, "goal_quantity");
+            assert_invalid(|c| c.goal_timeout = 0, "goal_timeout");
+            assert_invalid(|c| c.scan_distance = 0, "scan_distance");
+            assert_invalid(|c| c.min_threshold = -0.1, "min_threshold");
+            assert_invalid(|c| c.max_threshold = 1.5, "max_threshold");
+            assert_invalid(|c| { c.min_threshold = 0.8; c.max_threshold = 0.2; }, "min_threshold");
+            assert_invalid(|c| c.threshold = 0.95, "threshold");
+            assert_invalid(|c| c.energy_budget = c.min_discovery_budget - 1, "energy_budget");
+            assert_invalid(|c| c.resume_energy = c.min_energy - 1, "resume_energy");
+            assert_invalid(|c| c.content_candidates = 0, "content_candidates");
+            assert_invalid(|c| c.max_bridge_iterations = 0, "max_bridge_iterations");
+            assert_invalid(|c| c.direction = String::from("north"), "direction");
+            assert_invalid(|c| c.watchdog_window = 1, "watchdog_window");
+            assert_invalid(|c| c.event_window = 0, "event_window");
+            assert_invalid(|c| c.log_level = String::from("loud"), "log_level");
+        }
+
+        #[test]
+        fn direction_is_case_insensitive() {
+            let mut config = MinerConfig::default();
+            config.direction = String::from("DOWN");
+            assert!(config.validate().is_ok());
+            assert!(matches!(config.get_direction(), Direction::Down));
+        }
+
+        #[cfg(feature = "fair-play")]
+        #[test]
+        fn fair_play_cannot_be_disabled() {
+            assert_invalid(|c| c.fair_play = false, "fair_play");
+        }
+    }
+}
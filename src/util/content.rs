@@ -0,0 +1,274 @@
+pub mod content {
+    use std::collections::HashMap;
+    use std::mem::{discriminant, Discriminant};
+
+    // MinerRobot
+    use crate::Pos;
+
+    // robotics lib
+    use robotics_lib::world::tile::{Content, Tile};
+
+    /// Filters the content of a tile by its quantity
+    #[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+    pub enum QuantityFilter {
+        /// Any quantity matches
+        #[default]
+        Any,
+        /// The quantity must be at least the given one
+        AtLeast(usize),
+        /// The quantity must be exactly the given one
+        Exactly(usize)
+    }
+
+    impl QuantityFilter {
+        /// Checks if a quantity passes the filter
+        ///
+        /// # Arguments
+        ///
+        /// * `quantity` - the quantity of the content
+        ///
+        /// # Returns
+        ///
+        /// A bool stating whether the quantity passes the filter or not
+        pub fn matches(&self, quantity: usize) -> bool {
+            match self {
+                QuantityFilter::Any => true,
+                QuantityFilter::AtLeast(minimum) => quantity >= *minimum,
+                QuantityFilter::Exactly(exact) => quantity == *exact
+            }
+        }
+    }
+
+    /// Checks if two contents are of the same kind, ignoring their quantity
+    ///
+    /// # Arguments
+    ///
+    /// * `a` - the first content
+    /// * `b` - the second content
+    ///
+    /// # Returns
+    ///
+    /// A bool stating whether the two contents are the same variant, so Rock(1) and Rock(5) are of the same kind
+    pub fn same_kind(a: &Content, b: &Content) -> bool {
+        discriminant(a) == discriminant(b)
+    }
+
+    /// Returns the quantity held by a content
+    ///
+    /// # Arguments
+    ///
+    /// * `content` - the content
+    ///
+    /// # Returns
+    ///
+    /// The quantity of the content
+    ///
+    /// # Notes
+    ///
+    /// For the contents that hold a range (Bank, Bin and Crate) the quantity is the start of the range,
+    /// the contents without a value count as 1, except for Content::None
+    pub fn get_quantity(content: &Content) -> usize {
+        match content {
+            Content::Rock(quantity)
+            | Content::Tree(quantity)
+            | Content::Garbage(quantity)
+            | Content::Coin(quantity)
+            | Content::Water(quantity)
+            | Content::Market(quantity)
+            | Content::Fish(quantity)
+            | Content::Bush(quantity)
+            | Content::JollyBlock(quantity) => *quantity,
+            Content::Bank(range) | Content::Bin(range) | Content::Crate(range) => range.start,
+            Content::None => 0,
+            _ => 1
+        }
+    }
+
+    /// Index of the known tiles by kind of content, kept by the KnownMap
+    ///
+    /// # Notes
+    ///
+    /// Every kind of content maps the coordinates of its tiles to their quantity,
+    /// the tiles without content are not indexed
+    #[derive(Debug, Clone, Default)]
+    pub struct ContentIndex {
+        tiles: HashMap<Discriminant<Content>, HashMap<Pos, usize>>
+    }
+
+    impl ContentIndex {
+        /// Creates the index of a map
+        ///
+        /// # Arguments
+        ///
+        /// * `map` - the known world, where None is an unknown tile
+        pub fn from_map(map: &[Vec<Option<Tile>>]) -> Self {
+            let mut index = Self::default();
+            for (row, tiles_row) in map.iter().enumerate() {
+                for (col, tile) in tiles_row.iter().enumerate() {
+                    if let Some(tile) = tile {
+                        index.insert(Pos::new(row, col), &tile.content);
+                    }
+                }
+            }
+            index
+        }
+        /// Updates the index after the content of a tile changed
+        ///
+        /// # Arguments
+        ///
+        /// * `coordinates` - the coordinates of the tile
+        /// * `previous` - the previous content of the tile, None if the tile was unknown
+        /// * `content` - the new content of the tile
+        pub fn update(&mut self, coordinates: Pos, previous: Option<&Content>, content: &Content) {
+            if let Some(previous) = previous {
+                if let Some(tiles) = self.tiles.get_mut(&discriminant(previous)) {
+                    tiles.remove(&coordinates);
+                    if tiles.is_empty() {
+                        self.tiles.remove(&discriminant(previous));
+                    }
+                }
+            }
+            self.insert(coordinates, content);
+        }
+        /// Adds a tile to the index, unless it has no content
+        fn insert(&mut self, coordinates: Pos, content: &Content) {
+            if let Content::None = content {
+                return;
+            }
+            self.tiles.entry(discriminant(content)).or_default().insert(coordinates, get_quantity(content));
+        }
+        /// Returns the tiles containing content of the given kind
+        ///
+        /// # Arguments
+        ///
+        /// * `content` - the kind of content, its quantity is ignored
+        /// * `filter` - the filter on the quantity of the content
+        ///
+        /// # Returns
+        ///
+        /// A vector of tuples containing the coordinates of the tiles and the quantity of their content, in no particular order
+        pub fn get_tiles(&self, content: &Content, filter: QuantityFilter) -> Vec<(Pos, usize)> {
+            match self.tiles.get(&discriminant(content)) {
+                Some(tiles) => tiles.iter()
+                    .filter(|(_, quantity)| filter.matches(**quantity))
+                    .map(|(coordinates, quantity)| (*coordinates, *quantity))
+                    .collect(),
+                None => Vec::new()
+            }
+        }
+        /// Returns the total quantity of the given kind of content in the known tiles
+        ///
+        /// # Arguments
+        ///
+        /// * `content` - the kind of content, its quantity is ignored
+        pub fn get_total_quantity(&self, content: &Content) -> usize {
+            self.tiles.get(&discriminant(content)).map_or(0, |tiles| tiles.values().sum())
+        }
+        /// Returns the k nearest tiles containing content of the given kind
+        ///
+        /// # Arguments
+        ///
+        /// * `content` - the kind of content, its quantity is ignored
+        /// * `from` - the coordinates from which the distance is measured
+        /// * `k` - the maximum amount of tiles returned
+        /// * `radius` - the maximum Manhattan distance of the tiles, None for no limit
+        /// * `filter` - the filter on the quantity of the content
+        ///
+        /// # Returns
+        ///
+        /// A vector of tuples containing the coordinates of the tiles and the quantity of their content.
+        /// The closest tiles come first, the ones at the same distance are ordered by quantity, the highest first
+        pub fn get_nearest(&self, content: &Content, from: Pos, k: usize, radius: Option<usize>, filter: QuantityFilter) -> Vec<(Pos, usize)> {
+            let mut tiles: Vec<(Pos, usize)> = self.get_tiles(content, filter)
+                .into_iter()
+                .filter(|(coordinates, _)| match radius {
+                    Some(radius) => coordinates.distance(from) <= radius,
+                    None => true
+                })
+                .collect();
+            tiles.sort_by_key(|(coordinates, quantity)| (coordinates.distance(from), std::cmp::Reverse(*quantity), *coordinates));
+            tiles.truncate(k);
+            tiles
+        }
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use super::*;
+        use robotics_lib::world::tile::TileType;
+
+        fn tile(content: Content) -> Option<Tile> {
+            Some(Tile { tile_type: TileType::Grass, content, elevation: 0 })
+        }
+
+        fn index() -> ContentIndex {
+            ContentIndex::from_map(&[
+                vec![tile(Content::Rock(1)), None, tile(Content::Rock(4))],
+                vec![tile(Content::None), tile(Content::Tree(2)), tile(Content::Rock(2))],
+                vec![tile(Content::Rock(3)), None, None]
+            ])
+        }
+
+        #[test]
+        fn quantity_filter() {
+            assert!(QuantityFilter::Any.matches(0));
+            assert!(QuantityFilter::AtLeast(2).matches(3));
+            assert!(!QuantityFilter::AtLeast(2).matches(1));
+            assert!(QuantityFilter::Exactly(2).matches(2));
+            assert!(!QuantityFilter::Exactly(2).matches(3));
+        }
+
+        #[test]
+        fn indexes_the_known_tiles_by_kind() {
+            let index = index();
+            let mut rocks = index.get_tiles(&Content::Rock(0), QuantityFilter::Any);
+            rocks.sort();
+            assert_eq!(rocks, vec![(Pos::new(0, 0), 1), (Pos::new(0, 2), 4), (Pos::new(1, 2), 2), (Pos::new(2, 0), 3)]);
+            assert_eq!(index.get_total_quantity(&Content::Rock(0)), 10);
+            assert_eq!(index.get_total_quantity(&Content::Tree(0)), 2);
+            assert!(index.get_tiles(&Content::None, QuantityFilter::Any).is_empty());
+            assert_eq!(index.get_tiles(&Content::Rock(0), QuantityFilter::AtLeast(3)).len(), 2);
+        }
+
+        #[test]
+        fn update_moves_a_tile_between_kinds() {
+            let mut index = index();
+            // the rock got collected
+            index.update(Pos::new(0, 2), Some(&Content::Rock(4)), &Content::None);
+            assert_eq!(index.get_total_quantity(&Content::Rock(0)), 6);
+
+            // the tree became a rock
+            index.update(Pos::new(1, 1), Some(&Content::Tree(2)), &Content::Rock(5));
+            assert!(index.get_tiles(&Content::Tree(0), QuantityFilter::Any).is_empty());
+            assert_eq!(index.get_tiles(&Content::Rock(0), QuantityFilter::Exactly(5)), vec![(Pos::new(1, 1), 5)]);
+
+            // an unknown tile got discovered
+            index.update(Pos::new(2, 2), None, &Content::Coin(7));
+            assert_eq!(index.get_total_quantity(&Content::Coin(0)), 7);
+        }
+
+        #[test]
+        fn get_nearest_orders_by_distance_then_quantity() {
+            let index = index();
+            let from = Pos::new(1, 1);
+            // (0, 0), (0, 2) and (2, 0) are at distance 2, (1, 2) at distance 1
+            let nearest = index.get_nearest(&Content::Rock(0), from, 3, None, QuantityFilter::Any);
+            assert_eq!(nearest, vec![(Pos::new(1, 2), 2), (Pos::new(0, 2), 4), (Pos::new(2, 0), 3)]);
+
+            assert_eq!(index.get_nearest(&Content::Rock(0), from, 10, Some(1), QuantityFilter::Any), vec![(Pos::new(1, 2), 2)]);
+            assert_eq!(index.get_nearest(&Content::Rock(0), from, 10, None, QuantityFilter::AtLeast(4)), vec![(Pos::new(0, 2), 4)]);
+            assert!(index.get_nearest(&Content::Rock(0), from, 0, None, QuantityFilter::Any).is_empty());
+            assert!(index.get_nearest(&Content::Fish(0), from, 10, None, QuantityFilter::Any).is_empty());
+        }
+
+        #[test]
+        fn quantity_and_kind_helpers() {
+            assert!(same_kind(&Content::Rock(1), &Content::Rock(5)));
+            assert!(!same_kind(&Content::Rock(1), &Content::Tree(1)));
+            assert_eq!(get_quantity(&Content::Rock(3)), 3);
+            assert_eq!(get_quantity(&Content::Bank(2..10)), 2);
+            assert_eq!(get_quantity(&Content::Fire), 1);
+            assert_eq!(get_quantity(&Content::None), 0);
+        }
+    }
+}
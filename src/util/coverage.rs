@@ -0,0 +1,67 @@
+pub mod coverage {
+    // MinerRobot
+    use crate::MinerRobot;
+
+    // robotics lib
+    use robotics_lib::world::tile::TileType;
+
+    /// How much of the world the robot has seen, used to compare exploration strategies
+    #[derive(Debug, Clone, Default, PartialEq)]
+    pub struct Coverage {
+        pub tick: usize,
+        pub total_tiles: usize,
+        pub discovered_tiles: usize,
+        pub discovered_percentage: f64,
+        /// For every discovered TileType: the amount of tiles and their percentage over the discovered tiles
+        pub tile_types: Vec<(TileType, usize, f64)>,
+        pub frontier_length: usize,
+        /// The discovered area of each island, from the biggest one
+        pub island_areas: Vec<usize>,
+        /// The tiles revealed since the previous update
+        pub new_tiles: usize
+    }
+
+    impl MinerRobot {
+        /// Calculates the coverage of the known world
+        ///
+        /// # Returns
+        ///
+        /// The Coverage of the robot's map, the new tiles are counted from the last stored coverage
+        pub fn get_coverage(&self) -> Coverage {
+            let known_map = self.known_map.get_options();
+
+            let total_tiles: usize = known_map.iter().map(|row| row.len()).sum();
+            let mut discovered_tiles = 0;
+            let mut tile_types: Vec<(TileType, usize, f64)> = Vec::new();
+            for tile in known_map.iter().flatten().flatten() {
+                discovered_tiles += 1;
+                match tile_types.iter_mut().find(|(tile_type, _, _)| *tile_type == tile.tile_type) {
+                    Some((_, amount, _)) => *amount += 1,
+                    None => tile_types.push((tile.tile_type.clone(), 1, 0.0))
+                }
+            }
+            for (_, amount, percentage) in tile_types.iter_mut() {
+                *percentage = *amount as f64 * 100.0 / discovered_tiles as f64;
+            }
+            tile_types.sort_by(|a, b| b.1.cmp(&a.1));
+
+            let mut island_areas: Vec<usize> = self.get_islands(&self.known_map).iter().map(|island| island.len()).collect();
+            island_areas.sort_by(|a, b| b.cmp(a));
+
+            Coverage {
+                tick: self.tick,
+                total_tiles,
+                discovered_tiles,
+                discovered_percentage: if total_tiles == 0 { 0.0 } else { discovered_tiles as f64 * 100.0 / total_tiles as f64 },
+                tile_types,
+                frontier_length: self.get_frontier_tiles(&self.known_map).len(),
+                island_areas,
+                new_tiles: discovered_tiles.saturating_sub(self.coverage.discovered_tiles)
+            }
+        }
+        /// Updates the stored coverage, called once per tick after the known map is refreshed
+        pub fn update_coverage(&mut self) {
+            self.coverage = self.get_coverage();
+        }
+    }
+}
@@ -3,128 +3,150 @@ pub mod debug {
     use crate::MinerRobot;
 
     // robotics lib
-    use robotics_lib::world::World;
     use robotics_lib::world::tile::{Content, Tile, TileType};
-    use robotics_lib::interface::robot_map;
 
     // other
-    use colored::Colorize;
+    use colored::{ColoredString, Colorize};
+    use log::{debug, info, log_enabled, Level};
     impl MinerRobot {
-        /// Prints all the discovered tiles content
-        ///
-        /// # Arguments
-        ///
-        /// * `world` - the world
-        pub fn print_discovered_tiles_content(&self, world: &World) {
-            print!("- ");
-            for (i, _row) in robot_map(world).unwrap().iter().enumerate() {
-                print!("{} ", i % 10);
+        /// Logs all the discovered tiles content
+        pub fn print_discovered_tiles_content(&self) {
+            if !log_enabled!(target: "map", Level::Debug) {
+                return;
+            }
+            let map = self.known_map.get_options();
+            let mut out = String::from("- ");
+            for i in 0..self.known_map.cols() {
+                out.push_str(&format!("{} ", i % 10));
             }
-            println!();
-            for (i, row) in robot_map(world).unwrap().iter().enumerate() {
+            out.push('\n');
+            for (i, row) in map.iter().enumerate() {
                 for (j, tile) in row.iter().enumerate() {
                     if j == 0 {
-                        print!("{} ", i % 10);
+                        out.push_str(&format!("{} ", i % 10));
                     }
                     if i == self.robot.coordinate.get_row() && j == self.robot.coordinate.get_col() {
-                        print!("! ");
+                        out.push_str("! ");
                     } else {
                         match tile {
                             None => {
-                                print!("- ")
+                                out.push_str("- ")
                             }
                             Some(t) => {
-                                Self::print_content(t.clone().content)
+                                out.push_str(&Self::content_symbol(t.clone().content).to_string())
                             }
                         };
                     }
                 }
-                println!();
+                out.push('\n');
             }
-            println!();
+            debug!(target: "map", "\n{}", out);
         }
-        /// Decides whether to print the tiles tile_type in unicode or not based on the world dimension
-        pub fn print_discovered_tiles_tile_type(&self, world: &World) {
-            let map = robot_map(world).unwrap();
-            if map.len() < 30 {
-                self.print_discovered_tiles_tile_type_unicode(&map);
-            } else {
-                self.print_discovered_tiles_tile_type_default(&map);
+        /// Decides whether to log the tiles tile_type in unicode or not based on the world dimension
+        pub fn print_discovered_tiles_tile_type(&self) {
+            if !log_enabled!(target: "map", Level::Debug) {
+                return;
             }
+            let map = self.known_map.get_options();
+            let out = if self.known_map.rows().max(self.known_map.cols()) < 30 {
+                self.print_discovered_tiles_tile_type_unicode(map)
+            } else {
+                self.print_discovered_tiles_tile_type_default(map)
+            };
+            debug!(target: "map", "\n{}", out);
         }
-        /// Prints all the discovered tiles tile_type
+        /// Renders all the discovered tiles tile_type
         ///
         /// # Arguments
         ///
-        /// * `world` - the world
-        fn print_discovered_tiles_tile_type_default(&self, map: &Vec<Vec<Option<Tile>>>) {
-            print!("- ");
-            for (i, _row) in map.iter().enumerate() {
-                print!("{} ", i % 10);
+        /// * `map` - the known world
+        fn print_discovered_tiles_tile_type_default(&self, map: &Vec<Vec<Option<Tile>>>) -> String {
+            let mut out = String::from("- ");
+            for i in 0..self.known_map.cols() {
+                out.push_str(&format!("{} ", i % 10));
             }
-            println!();
+            out.push('\n');
             for (i, row) in map.iter().enumerate() {
                 for (j, tile) in row.iter().enumerate() {
                     if j == 0 {
-                        print!("{} ", i % 10);
+                        out.push_str(&format!("{} ", i % 10));
                     }
                     if i == self.robot.coordinate.get_row() && j == self.robot.coordinate.get_col() {
-                        print!("! ");
+                        out.push_str("! ");
                     } else {
                         match tile {
                             None => {
-                                print!("- ")
+                                out.push_str("- ")
                             }
                             Some(t) => {
-                                Self::print_tile_type(t.clone().tile_type)
+                                out.push_str(&Self::tile_type_symbol(t.clone().tile_type).to_string())
                             }
                         };
                     }
                 }
-                println!();
+                out.push('\n');
             }
-            println!();
+            out
         }
-        /// Prints all the discovered tiles tile_type in unicode
+        /// Renders all the discovered tiles tile_type in unicode
         ///
         /// # Arguments
         ///
-        /// * `world` - the world
-        fn print_discovered_tiles_tile_type_unicode(&self, map: &Vec<Vec<Option<Tile>>>) {
-            print!("{:<4} ","- ");
-            for (i, _row) in map.iter().enumerate() {
-                print!("{:<4} ", i % 10);
+        /// * `map` - the known world
+        fn print_discovered_tiles_tile_type_unicode(&self, map: &Vec<Vec<Option<Tile>>>) -> String {
+            let mut out = format!("{:<4} ","- ");
+            for i in 0..self.known_map.cols() {
+                out.push_str(&format!("{:<4} ", i % 10));
             }
-            println!();
+            out.push('\n');
             for (i, row) in map.iter().enumerate() {
                 for (j, tile) in row.iter().enumerate() {
                     if j == 0 {
-                        print!("{:<4} ", i % 10);
+                        out.push_str(&format!("{:<4} ", i % 10));
                     }
                     if i == self.robot.coordinate.get_row() && j == self.robot.coordinate.get_col() {
-                        print!("{:<4}","\u{1F916}");
+                        out.push_str(&format!("{:<4}","\u{1F916}"));
                     } else {
                         match tile {
                             None => {
-                                print!("{:<4} ","-")
+                                out.push_str(&format!("{:<4} ","-"))
                             }
                             Some(t) => {
-                                Self::print_tile_type_unicode(t.clone().tile_type)
+                                out.push_str(&format!("{:<4}", Self::tile_type_unicode(t.clone().tile_type)))
                             }
                         };
                     }
                 }
-                println!();
+                out.push('\n');
             }
-            println!();
+            out
         }
-        /// Prints the respective letter to the content given
+        /// Logs the coverage of the known world
+        pub fn print_coverage(&self) {
+            let coverage = &self.coverage;
+            debug!(target: "discovery", "Coverage: {:.1}% ({}/{} tiles), {} new tiles, frontier length: {}",
+                   coverage.discovered_percentage, coverage.discovered_tiles, coverage.total_tiles,
+                   coverage.new_tiles, coverage.frontier_length);
+            for (tile_type, amount, percentage) in &coverage.tile_types {
+                debug!(target: "discovery", "\t- {:?}: {} ({:.1}%)", tile_type, amount, percentage);
+            }
+            debug!(target: "discovery", "Island areas: {:?}", coverage.island_areas);
+        }
+        /// Logs where the energy of the run has been used
+        pub fn print_energy_summary(&self) {
+            info!(target: "energy", "Energy used: {}", self.energy_ledger.get_total_spent());
+            for summary in self.energy_ledger.get_summary() {
+                info!(target: "energy", "\t- {:?} while {:?}: {} actions, {} energy",
+                      summary.action, summary.purpose, summary.count, summary.energy_spent);
+            }
+        }
+        /// Returns the respective letter to the content given
         ///
         /// # Arguments
         ///
         /// * `content` - the content that is getting printed
-        fn print_content(content: Content) {
-            print!("{}", match content {
+        fn content_symbol(content: Content) -> ColoredString {
+            match content {
                 Content::Bank(_) => { "A ".yellow() }
                 Content::Bin(_) => { "I ".yellow() }
                 Content::Building => { "B ".yellow() }
@@ -141,15 +163,15 @@ pub mod debug {
                 Content::Tree(_) => { "T ".yellow() }
                 Content::Water(_) => { "W ".yellow() }
                 Content::None => { "+ ".yellow() }
-            })
+            }
         }
-        /// Prints the respective letter to the TileType given
+        /// Returns the respective letter to the TileType given
         ///
         /// # Arguments
         ///
         /// * `tile_type` - the TileType that is getting printed
-        fn print_tile_type(tile_type: TileType) {
-            print!("{}", match tile_type {
+        fn tile_type_symbol(tile_type: TileType) -> ColoredString {
+            match tile_type {
                 TileType::DeepWater => { "D ".blue() }
                 TileType::Grass => { "G ".green() }
                 TileType::Hill => { "H ".green() }
@@ -161,15 +183,15 @@ pub mod debug {
                 TileType::Street => { "R ".red() }
                 TileType::Teleport(_) => { "T ".green() }
                 TileType::Wall => { "W ".green() }
-            })
+            }
         }
-        /// Prints the respective letter to the TileType given in unicode
+        /// Returns the respective symbol to the TileType given in unicode
         ///
         /// # Arguments
         ///
         /// * `tile_type` - the TileType that is getting printed
-        fn print_tile_type_unicode(tile_type: TileType) {
-            print!("{:<4}", match tile_type {
+        fn tile_type_unicode(tile_type: TileType) -> &'static str {
+            match tile_type {
                 TileType::DeepWater => { "\u{1F30A}"}
                 TileType::Grass => { "\u{1F33F}" }
                 TileType::Hill => { "\u{26F0}" }
@@ -181,7 +203,7 @@ pub mod debug {
                 TileType::Street => { "\u{1F309}"}
                 TileType::Teleport(_) => { "\u{1F504}" }
                 TileType::Wall => { "\u{1F6A1}" }
-            })
+            }
         }
     }
 }
\ No newline at end of file
@@ -1,49 +1,208 @@
 pub mod discovery {
     use robotics_lib::world::World;
-    use spyglass::spyglass::SpyglassResult;
-    use crate::{ENERGY_BUDGET, MinerRobot, THRESHOLD};
+    use spyglass::spyglass::{Spyglass, SpyglassResult};
+    use crate::{ActionKind, MinerError, MinerRobot, Purpose};
+    use log::{info, warn};
+
+    /// Results of a single discovery, used to tune the exploration
+    #[derive(Debug, Clone, PartialEq)]
+    pub struct ScanReport {
+        pub tick: usize,
+        pub energy_budget: usize,
+        pub threshold: f64,
+        pub tiles_revealed: usize,
+        pub energy_spent: usize,
+        pub tiles_per_energy: f64
+    }
 
     impl MinerRobot {
-        /// Calls the discover_world method if the world hasn't been scanned yet
+        /// Calls the discover_world method if the world hasn't been scanned yet or if a scan is still pending
         ///
         /// # Arguments
         ///
         /// * `world` - the known world
         /// * `distance` - the distance from the robot, indicating the area to discover
-        pub fn scan_world(&mut self, world: &mut World, distance: usize) {
-            if !self.world_scanned {
-                self.discover_world(world, distance);
-                self.world_scanned = true;
+        ///
+        /// # Returns
+        ///
+        /// A MinerError if the spyglass fails to discover the area
+        ///
+        /// # Notes
+        ///
+        /// A paused or stopped scan is resumed only once the robot has enough energy,
+        /// a scan that used up its budget gets a new one first
+        pub fn scan_world(&mut self, world: &mut World, distance: usize) -> Result<(), MinerError> {
+            if self.spyglass.is_some() {
+                if self.robot.energy.get_energy_level() >= self.config.min_energy {
+                    self.refill_scan_budget();
+                    return self.discover_world(world, true);
+                }
+            } else if !self.world_scanned {
+                let energy_budget = self.get_discovery_budget();
+                let threshold = self.get_discovery_threshold();
+                self.scan_budget = energy_budget;
+                self.scan_threshold = threshold;
+                self.spyglass = Some(self.create_spyglass(distance, energy_budget, threshold));
+                return self.discover_world(world, false);
+            }
+            Ok(())
+        }
+        /// Tops the budget of a pending scan back up, called after the robot recharged
+        ///
+        /// # Notes
+        ///
+        /// The budget only grows, so a scan that still has energy left keeps it
+        pub(crate) fn refill_scan_budget(&mut self) {
+            if self.spyglass.is_some() {
+                self.scan_budget = self.scan_budget.max(self.get_discovery_budget());
+            }
+        }
+        /// Returns the energy budget of a new scan
+        ///
+        /// # Returns
+        ///
+        /// The energy budget, which never goes past the energy the robot has above the minimum energy
+        ///
+        /// # Notes
+        ///
+        /// Starting from the configured energy budget, the budget gets smaller the more of the map is known and the closer the goals are to completion.
+        /// The minimum discovery budget is applied first, so the available energy always has the last word
+        fn get_discovery_budget(&self) -> usize {
+            let available_energy = self.robot.energy.get_energy_level().saturating_sub(self.config.min_energy);
+            let unknown_ratio = 1.0 - self.get_known_ratio();
+            let goal_ratio = self.get_remaining_goal_ratio();
+
+            let budget = self.config.energy_budget as f64 * (0.5 + 0.5 * unknown_ratio) * (0.5 + 0.5 * goal_ratio);
+            (budget as usize).max(self.config.min_discovery_budget).min(available_energy)
+        }
+        /// Returns the view threshold of a new scan
+        ///
+        /// # Returns
+        ///
+        /// The threshold, between the configured minimum and maximum threshold
+        ///
+        /// # Notes
+        ///
+        /// The more of the map is known, the higher the threshold, so the spyglass only spends energy on areas that are mostly unknown.
+        /// When the energy is low the threshold gets higher as well
+        fn get_discovery_threshold(&self) -> f64 {
+            let known_ratio = self.get_known_ratio();
+            let energy_ratio = (self.robot.energy.get_energy_level() as f64 / (self.config.min_energy * 10) as f64).min(1.0);
+
+            let threshold = self.config.threshold * (0.5 + known_ratio) * (1.5 - 0.5 * energy_ratio);
+            threshold.clamp(self.config.min_threshold, self.config.max_threshold)
+        }
+        /// Returns how much of the active goals is still missing
+        ///
+        /// # Returns
+        ///
+        /// The ratio between the remaining and the total quantity of the goals, 1.0 if there are no goals
+        fn get_remaining_goal_ratio(&self) -> f64 {
+            let total: usize = self.goal_records.iter().map(|r| r.goal_quantity as usize).sum();
+            if total == 0 {
+                return 1.0;
             }
+            let remaining: usize = self.goal_records.iter().map(|r| r.remaining()).sum();
+            remaining as f64 / total as f64
         }
-        /// Discovers the world around the robot
+        /// Discovers the world around the robot using the stored spyglass
         ///
         /// # Arguments
         ///
         /// * `world` - the known world
-        /// * `distance` - the distance from the robot, indicating the area to discover
-        fn discover_world(&mut self, world: &mut World, distance: usize) {
-            // generating a new spyglass by setting an energy budget and a threshold
-            let mut spyglass = self.create_spyglass(world, distance, ENERGY_BUDGET, THRESHOLD);
+        /// * `resumed` - whether the spyglass is resuming a pending scan
+        ///
+        /// # Returns
+        ///
+        /// A MinerError if the spyglass fails
+        ///
+        /// # Notes
+        ///
+        /// If the scan gets paused or stopped the spyglass is kept, together with what is left of the energy budget,
+        /// so that the scan can go on during the next ticks. Only a complete or failed scan marks the area as scanned
+        fn discover_world(&mut self, world: &mut World, resumed: bool) -> Result<(), MinerError> {
+            let mut spyglass = match self.spyglass.take() {
+                Some(spyglass) => spyglass,
+                None => return Ok(())
+            };
+
+            // the spyglass can only use what is left of the budget
+            spyglass.set_energy_budget(Some(self.scan_budget));
+            let energy = self.robot.energy.get_energy_level();
+            let discovered_tiles = self.count_discovered_tiles();
 
-            // discovering tiles around the robot
-            let result = spyglass.new_discover(self, world);
+            // discovering tiles around the robot, the known map is read again since the spyglass doesn't send events
+            let result = self.metered(ActionKind::Discover, Purpose::Scanning, |robot| spyglass.new_discover(robot, world));
+            self.refresh_known_map(world);
+            let energy_spent = energy.saturating_sub(self.robot.energy.get_energy_level());
+            let tiles_revealed = self.count_discovered_tiles().saturating_sub(discovered_tiles);
+            self.scan_budget = self.scan_budget.saturating_sub(energy_spent);
+            self.log_scan_report(tiles_revealed, energy_spent);
+            // a resumed scan that does nothing would be resumed forever
+            let stalled = resumed && tiles_revealed == 0 && energy_spent == 0;
 
             // managing result
             match result {
                 SpyglassResult::Complete(_) => {
-                    println!("Scan Complete!");
+                    info!(target: "discovery", "Scan Complete!");
+                    self.world_scanned = true;
                 },
                 SpyglassResult::Failed(_) => {
-                    println!("Scan Failed!");
+                    warn!(target: "discovery", "Scan Failed!");
+                    self.world_scanned = true;
+                    return Err(MinerError::Spyglass {
+                        tile: self.get_coordinates(),
+                        reason: String::from("the spyglass could not discover the area")
+                    });
                 },
                 SpyglassResult::Paused => {
-                    println!("Scan Paused!");
+                    info!(target: "discovery", "Scan Paused! {} energy left in the budget", self.scan_budget);
+                    self.keep_pending_scan(spyglass, stalled);
                 },
                 SpyglassResult::Stopped(_) => {
-                    println!("Scan Stopped!");
+                    info!(target: "discovery", "Scan Stopped! {} energy left in the budget", self.scan_budget);
+                    self.keep_pending_scan(spyglass, stalled);
                 }
             }
+            Ok(())
+        }
+        /// Stores and prints the results of a discovery
+        ///
+        /// # Arguments
+        ///
+        /// * `tiles_revealed` - the amount of tiles discovered
+        /// * `energy_spent` - the energy used by the spyglass
+        fn log_scan_report(&mut self, tiles_revealed: usize, energy_spent: usize) {
+            let report = ScanReport {
+                tick: self.tick,
+                energy_budget: self.scan_budget + energy_spent,
+                threshold: self.scan_threshold,
+                tiles_revealed,
+                energy_spent,
+                tiles_per_energy: if energy_spent == 0 { 0.0 } else { tiles_revealed as f64 / energy_spent as f64 }
+            };
+            info!(target: "discovery", "Scan report: {} tiles revealed, {} energy spent, {:.2} tiles per energy (budget {}, threshold {:.2})",
+                     report.tiles_revealed, report.energy_spent, report.tiles_per_energy, report.energy_budget, report.threshold);
+            self.scan_reports.push(report);
+        }
+        /// Keeps the spyglass of a scan that has not ended, so that it can be resumed
+        ///
+        /// # Arguments
+        ///
+        /// * `spyglass` - the spyglass of the pending scan
+        /// * `stalled` - whether the scan was resumed without revealing anything or spending any energy
+        ///
+        /// # Notes
+        ///
+        /// A scan that used up its budget is kept too, it gets a new budget once the robot recharged.
+        /// A stalled scan cannot go on, so it is dropped and the area is marked as scanned
+        fn keep_pending_scan(&mut self, spyglass: Spyglass, stalled: bool) {
+            if stalled {
+                info!(target: "discovery", "The pending scan made no progress, the area is marked as scanned");
+                self.world_scanned = true;
+            } else {
+                self.spyglass = Some(spyglass);
+            }
         }
     }
-}
\ No newline at end of file
+}
@@ -0,0 +1,117 @@
+pub mod error {
+    use std::fmt::{Display, Formatter};
+
+    // MinerRobot
+    use crate::{ActionKind, ConfigError, MinerRobot};
+
+    // robotics lib
+    use robotics_lib::utils::LibError;
+    use robotics_lib::world::World;
+
+    // tools
+    use bessie::bessie::RpmError;
+
+    /// Errors that can happen while the robot works
+    #[derive(Debug)]
+    pub enum MinerError {
+        /// An interface of robotics_lib failed
+        Lib {
+            /// The action that failed
+            action: ActionKind,
+            /// The tile the action was aimed at
+            tile: (usize, usize),
+            error: LibError
+        },
+        /// The road paving machine failed
+        Rpm {
+            /// The tile the robot was on
+            tile: (usize, usize),
+            error: RpmError
+        },
+        /// The spyglass could not discover the area
+        Spyglass {
+            /// The center of the scan
+            tile: (usize, usize),
+            reason: String
+        },
+        /// There is no way to do what the robot planned
+        Planning {
+            /// The target of the plan, if there is one
+            tile: Option<(usize, usize)>,
+            reason: String
+        },
+        /// The configuration is not valid
+        Config(ConfigError)
+    }
+
+    impl Display for MinerError {
+        fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+            match self {
+                MinerError::Lib { action, tile, error } => write!(f, "{:?} on {:?} failed: {:?}", action, tile, error),
+                MinerError::Rpm { tile, error } => write!(f, "Road paving machine on {:?} failed: {}", tile, rpm_error_message(error)),
+                MinerError::Spyglass { tile, reason } => write!(f, "Scan around {:?} failed: {}", tile, reason),
+                MinerError::Planning { tile: Some(tile), reason } => write!(f, "Cannot plan towards {:?}: {}", tile, reason),
+                MinerError::Planning { tile: None, reason } => write!(f, "Cannot plan: {}", reason),
+                MinerError::Config(e) => write!(f, "{}", e)
+            }
+        }
+    }
+
+    impl std::error::Error for MinerError {}
+
+    impl From<ConfigError> for MinerError {
+        fn from(error: ConfigError) -> Self {
+            MinerError::Config(error)
+        }
+    }
+
+    /// Returns the description of a RpmError
+    pub(crate) fn rpm_error_message(error: &RpmError) -> &'static str {
+        match error {
+            RpmError::NotEnoughEnergy => "Not enough energy",
+            RpmError::CannotPlaceHere => "Cannot place content on the current tile",
+            RpmError::OutOfBounds => "Out of bounds",
+            RpmError::NotEnoughMaterial => "Not enough material",
+            RpmError::NoRockHere => "No rock here",
+            RpmError::MustDestroyContentFirst => "Must destroy content first",
+            RpmError::UndefinedError => "Undefined error"
+        }
+    }
+
+    impl MinerRobot {
+        /// Handles a LibError and wraps it into a MinerError
+        ///
+        /// # Arguments
+        ///
+        /// * `world` - the world
+        /// * `action` - the action that failed
+        /// * `tile` - the tile the action was aimed at
+        /// * `error` - the LibError
+        ///
+        /// # Returns
+        ///
+        /// The MinerError to return to the caller
+        ///
+        /// # Notes
+        ///
+        /// The robot still recharges if the error is caused by the lack of energy
+        pub(crate) fn lib_failure(&mut self, world: &mut World, action: ActionKind, tile: (usize, usize), error: LibError) -> MinerError {
+            self.catch_lib_error(world, &error);
+            MinerError::Lib { action, tile, error }
+        }
+        /// Handles a RpmError and wraps it into a MinerError
+        ///
+        /// # Arguments
+        ///
+        /// * `world` - the world
+        /// * `error` - the RpmError
+        ///
+        /// # Returns
+        ///
+        /// The MinerError to return to the caller
+        pub(crate) fn rpm_failure(&mut self, world: &mut World, error: RpmError) -> MinerError {
+            self.catch_rpm_error(world, &error);
+            MinerError::Rpm { tile: self.get_coordinates(), error }
+        }
+    }
+}
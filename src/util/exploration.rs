@@ -0,0 +1,136 @@
+pub mod exploration {
+    // MinerRobot
+    use crate::{KnownMap, MinerError, MinerRobot, Pos, Purpose};
+
+    // robotics lib
+    use robotics_lib::world::World;
+
+    // other
+    use log::info;
+
+    impl MinerRobot {
+        /// Moves the robot to the most promising frontier and scans the area around it
+        ///
+        /// # Arguments
+        ///
+        /// * `world` - the world
+        ///
+        /// # Returns
+        ///
+        /// A bool stating whether a reachable frontier was found or not,
+        /// or a MinerError if the robot cannot get to the frontier or cannot scan it
+        ///
+        /// # Notes
+        ///
+        /// The frontier is marked as explored, so that a scan that reveals nothing is not repeated
+        /// until the known map changes
+        pub fn explore_frontier(&mut self, world: &mut World) -> Result<bool, MinerError> {
+            let frontier = match self.get_best_frontier(world) {
+                Some(frontier) => frontier,
+                None => return Ok(false)
+            };
+            info!(target: "discovery", "Exploring the frontier at {:?}", frontier);
+            self.explored_frontiers.insert(frontier);
+
+            // moving to the frontier and scanning once the robot gets there
+            self.move_to_coords(world, frontier, Purpose::Exploring)?;
+            if self.waiting_for_energy {
+                return Ok(true);
+            }
+            // a scan that is still pending belongs to another area, so a new one is started
+            self.spyglass = None;
+            self.world_scanned = false;
+            self.scan_world(world, self.scan_distance)?;
+            Ok(true)
+        }
+        /// Returns the reachable frontier with the best expected gain per unit of energy
+        ///
+        /// # Arguments
+        ///
+        /// * `world` - the world
+        ///
+        /// # Returns
+        ///
+        /// An option of coordinates, None if there is no reachable frontier left
+        ///
+        /// # Notes
+        ///
+        /// The expected gain is the amount of unknown tiles in the scan distance and the energy is the Lssf cost to reach the frontier.
+        /// The explored frontiers are forgotten when the known map changes, since their gain changed too
+        pub fn get_best_frontier(&mut self, world: &mut World) -> Option<Pos> {
+            let robot = self.get_position();
+            if self.explored_frontiers_version != Some(self.known_map.get_version()) {
+                self.explored_frontiers.clear();
+                self.explored_frontiers_version = Some(self.known_map.get_version());
+            }
+
+            // updating the costs from the robot's coordinates
+            self.update_lssf_map_and_cost(world, robot.row, robot.col);
+
+            let known_map = &self.known_map;
+            let mut best_frontier = None;
+            let mut best_score = 0.0;
+            for frontier in self.get_frontier_tiles(known_map) {
+                if frontier == robot || self.explored_frontiers.contains(&frontier) {
+                    continue;
+                }
+                let cost = match self.lssf.get_cost(frontier.row, frontier.col) {
+                    Some(cost) => cost,
+                    None => continue
+                };
+                let gain = self.get_expected_gain(known_map, frontier, self.scan_distance);
+                let score = gain as f64 / (cost + 1) as f64;
+                if score > best_score {
+                    best_score = score;
+                    best_frontier = Some(frontier);
+                }
+            }
+            best_frontier
+        }
+        /// Returns the frontier tiles, which are the known walkable tiles next to at least one unknown tile
+        ///
+        /// # Arguments
+        ///
+        /// * `map` - the known world
+        ///
+        /// # Returns
+        ///
+        /// A vector containing the coordinates of the frontier tiles
+        pub fn get_frontier_tiles(&self, map: &KnownMap) -> Vec<Pos> {
+            let tiles = map.get_tiles();
+            tiles.iter()
+                .map(|(pos, _)| pos)
+                .filter(|pos| map.get(*pos).is_some_and(|tile| self.is_walkable(&tile.tile_type)))
+                // checking if any of the neighbours is unknown
+                .filter(|pos| tiles.neighbours(*pos).any(|(_direction, next)| map.get(next).is_none()))
+                .collect()
+        }
+        /// Counts the unknown tiles that a scan from the given coordinates could reveal
+        ///
+        /// # Arguments
+        ///
+        /// * `map` - the known world
+        /// * `pos` - the coordinates of the scan
+        /// * `distance` - the distance of the scan
+        ///
+        /// # Returns
+        ///
+        /// The amount of unknown tiles within the distance
+        fn get_expected_gain(&self, map: &KnownMap, pos: Pos, distance: usize) -> usize {
+            let map = map.get_options();
+            let first_row = pos.row.saturating_sub(distance);
+            let last_row = (pos.row + distance).min(map.len().saturating_sub(1));
+
+            let mut gain = 0;
+            // the rows are checked one by one, so that empty maps and rows of different lengths are skipped
+            for tiles_row in map.iter().take(last_row + 1).skip(first_row) {
+                let first_col = pos.col.saturating_sub(distance);
+                let last_col = (pos.col + distance).min(tiles_row.len().saturating_sub(1));
+                if first_col < tiles_row.len() {
+                    gain += tiles_row[first_col..=last_col].iter().filter(|tile| tile.is_none()).count();
+                }
+            }
+            gain
+        }
+    }
+}
@@ -1,7 +1,9 @@
 pub mod goal {
+    use std::collections::HashMap;
+    use std::mem::discriminant;
+
     // MinerRobot
-    use crate::MinerRobot;
-    use crate::GOAL_QUANTITY;
+    use crate::{same_kind, GoalRecord, MinerRobot};
 
     // robotics lib
     use robotics_lib::world::tile::Content;
@@ -11,10 +13,15 @@ pub mod goal {
 
 
     impl MinerRobot {
-        /// Sets a new goal if the tracker is empty, otherwise it cleans the completed goals
+        /// Sets a new goal if there is no active goal, otherwise it cleans the completed goals of the tracker
+        ///
+        /// # Notes
+        ///
+        /// The goals that have not been completed in time expire before anything else
         pub fn handle_goals(&mut self) {
-            if self.goal_tracker.get_goals().len() == 0 {
-                self.set_goal_by_content(GoalType::GetItems, Content::Rock(1), GOAL_QUANTITY);
+            self.expire_goals();
+            if self.goal_records.is_empty() {
+                self.set_goal_by_content(GoalType::GetItems, Content::Rock(1), self.config.goal_quantity);
             } else {
                 self.goal_tracker.clean_completed_goals();
             }
@@ -27,13 +34,108 @@ pub mod goal {
         /// * `content` - the content associated to the goal
         /// * `goal_quantity` - the quantity necessary to complete the goal
         pub fn set_goal_by_content(&mut self, goal_type: GoalType, content: Content, goal_quantity: u32) {
+            self.set_goal(goal_type, Some(content), goal_quantity);
+        }
+        /// Creates a new goal for the robot and adds it to the robot's goal tracker
+        ///
+        /// # Arguments
+        ///
+        /// * `goal_type` - the type of goal that will get created
+        /// * `content` - the content associated to the goal, None for the goals without content (e.g. reaching coordinates)
+        /// * `goal_quantity` - the quantity necessary to complete the goal
+        pub fn set_goal(&mut self, goal_type: GoalType, content: Option<Content>, goal_quantity: u32) {
+            let (name, description) = match &content {
+                Some(content) => (Self::get_goal_name_by_content(content), Self::get_goal_description_by_content(content)),
+                None => (String::from("Reaching coordinates"), String::from("Walking around"))
+            };
+            let record = GoalRecord {
+                name,
+                description,
+                goal_type: goal_type.clone(),
+                content: content.clone(),
+                goal_quantity,
+                progress: 0,
+                created_at: self.tick
+            };
             self.goal_tracker.add_goal(Goal::new(
-                Self::get_goal_name_by_content(&content),
-                Self::get_goal_description_by_content(&content),
+                record.name.clone(),
+                record.description.clone(),
                 goal_type,
-                Some(content),
+                content,
                 goal_quantity
             ));
+            self.record_goal_created(record);
+        }
+        /// Reports the progress made by an action to the goal tracker
+        ///
+        /// # Arguments
+        ///
+        /// * `goal_type` - the type of goal that the action advances
+        /// * `content` - the content involved in the action, None if the action has no content (e.g. reaching coordinates)
+        /// * `quantity` - the amount of progress made
+        ///
+        /// # Notes
+        ///
+        /// The backpack holds contents such as Rock(0) while the goals hold Rock(1), so the content is first
+        /// replaced by the one of the active goal of the same type and kind. The records and the tracker then match it exactly
+        pub fn update_goal(&mut self, goal_type: GoalType, content: Option<Content>, quantity: usize) {
+            if quantity == 0 {
+                return;
+            }
+            let content = match content {
+                Some(content) => self.goal_records.iter()
+                    .filter(|record| discriminant(&record.goal_type) == discriminant(&goal_type))
+                    .filter_map(|record| record.content.as_ref())
+                    .find(|goal_content| same_kind(goal_content, &content))
+                    .cloned()
+                    .or(Some(content)),
+                None => None
+            };
+            // the tracker only hears about the active goals, the expired ones are not advanced anymore
+            if self.goal_records.iter().any(|record| record.matches(&goal_type, &content)) {
+                self.goal_tracker.update_manual(goal_type.clone(), content.clone(), quantity);
+            }
+            self.record_goal_progress(&goal_type, &content, quantity);
+        }
+        /// Reports the coins earned since the given amount to the goal tracker
+        ///
+        /// # Arguments
+        ///
+        /// * `coins_before` - the amount of coins in the backpack before the action
+        pub fn report_coins_earned(&mut self, coins_before: usize) {
+            let coins = self.get_backpack_quantity(&Content::Coin(0));
+            if coins > coins_before {
+                self.update_goal(GoalType::EarnCoins, Some(Content::Coin(0)), coins - coins_before);
+            }
+        }
+        /// Reports every content that got added to the backpack to the goal tracker
+        ///
+        /// # Arguments
+        ///
+        /// * `backpack_before` - the backpack's contents before the action
+        ///
+        /// # Notes
+        ///
+        /// Used for the tools that collect several contents at once, where the single quantities are not returned
+        pub fn report_backpack_gains(&mut self, backpack_before: HashMap<Content, usize>) {
+            let gains: Vec<(Content, usize)> = self.robot.backpack.get_contents()
+                .iter()
+                .filter_map(|(content, quantity)| {
+                    let before = backpack_before.get(content).copied().unwrap_or(0);
+                    if *quantity > before {
+                        Some((content.clone(), quantity - before))
+                    } else {
+                        None
+                    }
+                })
+                .collect();
+
+            for (content, quantity) in gains {
+                if let Content::Coin(_) = content {
+                    self.update_goal(GoalType::EarnCoins, Some(content.clone()), quantity);
+                }
+                self.update_goal(GoalType::GetItems, Some(content), quantity);
+            }
         }
         /// Returns the goal's name given a content
         ///
@@ -94,4 +196,63 @@ pub mod goal {
             })
         }
     }
-}
\ No newline at end of file
+
+    #[cfg(test)]
+    mod tests {
+        use super::*;
+        use crate::GoalEventKind;
+
+        #[test]
+        fn get_items_goal_counts_the_backpack_content() {
+            let mut robot = MinerRobot::new();
+            robot.set_goal_by_content(GoalType::GetItems, Content::Rock(1), 3);
+            robot.update_goal(GoalType::GetItems, Some(Content::Rock(0)), 2);
+            assert_eq!(robot.goal_records[0].progress, 2);
+            robot.update_goal(GoalType::GetItems, Some(Content::Rock(0)), 1);
+            assert!(robot.goal_records.is_empty());
+            assert_eq!(robot.goals_completed, 1);
+        }
+
+        #[test]
+        fn put_items_goal_does_not_take_the_get_items_progress() {
+            let mut robot = MinerRobot::new();
+            robot.set_goal_by_content(GoalType::PutItems, Content::Rock(3), 4);
+            robot.set_goal_by_content(GoalType::GetItems, Content::Rock(1), 4);
+
+            robot.update_goal(GoalType::GetItems, Some(Content::Rock(0)), 2);
+            robot.update_goal(GoalType::PutItems, Some(Content::Rock(0)), 1);
+
+            let progress = |goal_type: GoalType| robot.goal_records.iter()
+                .find(|record| discriminant(&record.goal_type) == discriminant(&goal_type))
+                .map(|record| record.progress);
+            assert_eq!(progress(GoalType::GetItems), Some(2));
+            assert_eq!(progress(GoalType::PutItems), Some(1));
+        }
+
+        #[test]
+        fn reach_coordinates_goal_has_no_content() {
+            let mut robot = MinerRobot::new();
+            let receiver = robot.subscribe_goal_events();
+            robot.set_goal(GoalType::ReachCoordinates, None, 1);
+            assert_eq!(robot.goal_records[0].content, None);
+
+            robot.update_goal(GoalType::ReachCoordinates, None, 1);
+            let kinds: Vec<GoalEventKind> = receiver.try_iter().map(|event| event.kind).collect();
+            assert_eq!(kinds, vec![
+                GoalEventKind::Created,
+                GoalEventKind::Progressed { quantity: 1, progress: 1 },
+                GoalEventKind::Completed
+            ]);
+        }
+
+        #[test]
+        fn earn_coins_goal_counts_the_coins() {
+            let mut robot = MinerRobot::new();
+            robot.set_goal_by_content(GoalType::EarnCoins, Content::Coin(1), 10);
+            robot.update_goal(GoalType::EarnCoins, Some(Content::Coin(0)), 4);
+            // coins collected as items don't advance the goal twice
+            robot.update_goal(GoalType::GetItems, Some(Content::Coin(0)), 4);
+            assert_eq!(robot.goal_records[0].progress, 4);
+        }
+    }
+}
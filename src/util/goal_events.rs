@@ -0,0 +1,271 @@
+pub mod goal_events {
+    use std::mem::discriminant;
+    use std::sync::mpsc::{channel, Receiver, Sender};
+
+    // MinerRobot
+    use crate::MinerRobot;
+
+    // robotics lib
+    use robotics_lib::world::tile::Content;
+
+    // tool
+    use bob_lib::tracker::GoalType;
+
+    /// The step of a goal's lifecycle an event refers to
+    #[derive(Debug, Clone, PartialEq)]
+    pub enum GoalEventKind {
+        Created,
+        Progressed { quantity: usize, progress: usize },
+        Completed,
+        Failed,
+        Expired
+    }
+
+    /// Event sent to the listeners every time a goal changes
+    #[derive(Debug, Clone)]
+    pub struct GoalEvent {
+        pub tick: usize,
+        pub name: String,
+        pub goal_type: GoalType,
+        pub content: Option<Content>,
+        pub kind: GoalEventKind
+    }
+
+    /// Keeps track of a goal added to the goal tracker and of the progress made on it
+    #[derive(Debug, Clone)]
+    pub struct GoalRecord {
+        pub name: String,
+        pub description: String,
+        pub goal_type: GoalType,
+        pub content: Option<Content>,
+        pub goal_quantity: u32,
+        pub progress: usize,
+        pub created_at: usize
+    }
+
+    /// Callback that gets called for every goal event
+    pub type GoalCallback = Box<dyn FnMut(&GoalEvent) + Send>;
+
+    impl GoalRecord {
+        /// Returns whether an action on the given goal type and content advances this goal
+        ///
+        /// # Arguments
+        ///
+        /// * `goal_type` - the type of goal advanced by the action
+        /// * `content` - the content involved in the action
+        ///
+        /// # Returns
+        ///
+        /// A bool, the content is compared exactly like the goal tracker does, quantity included,
+        /// so the records and the tracker always advance the same goals
+        pub(crate) fn matches(&self, goal_type: &GoalType, content: &Option<Content>) -> bool {
+            discriminant(&self.goal_type) == discriminant(goal_type) && self.content == *content
+        }
+        /// Returns the quantity that is still missing to complete the goal
+        pub fn remaining(&self) -> usize {
+            (self.goal_quantity as usize).saturating_sub(self.progress)
+        }
+    }
+
+    impl MinerRobot {
+        /// Registers a callback that gets called for every goal event
+        ///
+        /// # Arguments
+        ///
+        /// * `callback` - the function called with the event
+        pub fn on_goal_event(&mut self, callback: impl FnMut(&GoalEvent) + Send + 'static) {
+            self.goal_callbacks.push(Box::new(callback));
+        }
+        /// Creates a channel that receives every goal event
+        ///
+        /// # Returns
+        ///
+        /// The receiving end of the channel
+        pub fn subscribe_goal_events(&mut self) -> Receiver<GoalEvent> {
+            let (sender, receiver): (Sender<GoalEvent>, Receiver<GoalEvent>) = channel();
+            self.goal_senders.push(sender);
+            receiver
+        }
+        /// Adds the record of a new goal and sends the Created event
+        ///
+        /// # Arguments
+        ///
+        /// * `record` - the record of the goal that has been added to the tracker
+        pub(crate) fn record_goal_created(&mut self, record: GoalRecord) {
+            self.emit_goal_event(&record, GoalEventKind::Created);
+            self.goal_records.push(record);
+        }
+        /// Adds the progress to the matching goals and sends the Progressed and Completed events
+        ///
+        /// # Arguments
+        ///
+        /// * `goal_type` - the type of goal advanced by the action
+        /// * `content` - the content involved in the action
+        /// * `quantity` - the amount of progress made
+        pub(crate) fn record_goal_progress(&mut self, goal_type: &GoalType, content: &Option<Content>, quantity: usize) {
+            let mut events = Vec::new();
+            for record in self.goal_records.iter_mut().filter(|r| r.matches(goal_type, content)) {
+                record.progress += quantity;
+                events.push((record.clone(), GoalEventKind::Progressed { quantity, progress: record.progress }));
+                if record.remaining() == 0 {
+                    events.push((record.clone(), GoalEventKind::Completed));
+                }
+            }
+
+            for (record, kind) in events {
+                if kind == GoalEventKind::Completed {
+                    self.goals_completed += 1;
+                }
+                self.emit_goal_event(&record, kind);
+            }
+            self.goal_records.retain(|r| r.remaining() > 0);
+        }
+        /// Removes the goals that have not been completed within the goal timeout and sends the Expired events
+        ///
+        /// # Notes
+        ///
+        /// The goal tracker has no way to remove a single goal, so an expired goal is driven to the end in the tracker
+        /// and cleaned with the completed ones. The records are the active goals and the robot counts the completed ones itself,
+        /// so the count of the tracker is not used. A goal that matches an active one stays in the tracker,
+        /// closing it would advance the active one too
+        pub(crate) fn expire_goals(&mut self) {
+            let tick = self.tick;
+            let timeout = self.config.goal_timeout;
+            let (expired, active): (Vec<GoalRecord>, Vec<GoalRecord>) = self.goal_records
+                .drain(..)
+                .partition(|r| tick.saturating_sub(r.created_at) >= timeout);
+            self.goal_records = active;
+
+            if expired.is_empty() {
+                return;
+            }
+            for record in &expired {
+                self.emit_goal_event(record, GoalEventKind::Expired);
+                if !self.goal_records.iter().any(|r| r.matches(&record.goal_type, &record.content)) {
+                    self.goal_tracker.update_manual(record.goal_type.clone(), record.content.clone(), record.remaining());
+                }
+            }
+            self.goal_tracker.clean_completed_goals();
+        }
+        /// Sends the Failed event for every goal that is still active, called when the game ends
+        pub(crate) fn fail_pending_goals(&mut self) {
+            for record in std::mem::take(&mut self.goal_records) {
+                self.emit_goal_event(&record, GoalEventKind::Failed);
+            }
+        }
+        /// Sends a goal event to every callback and channel
+        ///
+        /// # Arguments
+        ///
+        /// * `record` - the goal the event refers to
+        /// * `kind` - the kind of event
+        fn emit_goal_event(&mut self, record: &GoalRecord, kind: GoalEventKind) {
+            let event = GoalEvent {
+                tick: self.tick,
+                name: record.name.clone(),
+                goal_type: record.goal_type.clone(),
+                content: record.content.clone(),
+                kind
+            };
+
+            for callback in self.goal_callbacks.iter_mut() {
+                callback(&event);
+            }
+            // the channels whose receiver got dropped are removed
+            self.goal_senders.retain(|sender| sender.send(event.clone()).is_ok());
+        }
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use super::*;
+        use std::sync::{Arc, Mutex};
+
+        fn kinds(receiver: &Receiver<GoalEvent>) -> Vec<GoalEventKind> {
+            receiver.try_iter().map(|event| event.kind).collect()
+        }
+
+        #[test]
+        fn completed_goal_sends_every_event() {
+            let mut robot = MinerRobot::new();
+            let receiver = robot.subscribe_goal_events();
+            robot.set_goal_by_content(GoalType::GetItems, Content::Rock(1), 3);
+            robot.update_goal(GoalType::GetItems, Some(Content::Rock(0)), 1);
+            robot.update_goal(GoalType::GetItems, Some(Content::Rock(0)), 2);
+
+            assert_eq!(kinds(&receiver), vec![
+                GoalEventKind::Created,
+                GoalEventKind::Progressed { quantity: 1, progress: 1 },
+                GoalEventKind::Progressed { quantity: 2, progress: 3 },
+                GoalEventKind::Completed
+            ]);
+            assert_eq!(robot.goals_completed, 1);
+            assert!(robot.goal_records.is_empty());
+        }
+
+        #[test]
+        fn goal_expires_after_the_timeout() {
+            let mut robot = MinerRobot::new();
+            robot.config.goal_timeout = 10;
+            let receiver = robot.subscribe_goal_events();
+            robot.set_goal_by_content(GoalType::GetItems, Content::Rock(1), 3);
+
+            robot.tick = 9;
+            robot.expire_goals();
+            assert_eq!(robot.goal_records.len(), 1);
+
+            robot.tick = 10;
+            robot.expire_goals();
+            assert!(robot.goal_records.is_empty());
+            assert_eq!(kinds(&receiver), vec![GoalEventKind::Created, GoalEventKind::Expired]);
+
+            // the expired goal doesn't make progress anymore
+            robot.update_goal(GoalType::GetItems, Some(Content::Rock(1)), 3);
+            assert!(kinds(&receiver).is_empty());
+            assert_eq!(robot.goals_completed, 0);
+        }
+
+        #[test]
+        fn pending_goals_fail_at_the_end() {
+            let mut robot = MinerRobot::new();
+            let receiver = robot.subscribe_goal_events();
+            robot.set_goal_by_content(GoalType::GetItems, Content::Rock(1), 3);
+            robot.update_goal(GoalType::GetItems, Some(Content::Rock(0)), 1);
+            robot.fail_pending_goals();
+
+            let events: Vec<GoalEvent> = receiver.try_iter().collect();
+            assert_eq!(events.last().map(|event| event.kind.clone()), Some(GoalEventKind::Failed));
+            assert_eq!(events.last().map(|event| event.name.clone()), Some(String::from("Looking for Rocks")));
+            assert!(robot.goal_records.is_empty());
+        }
+
+        #[test]
+        fn callbacks_and_channels_get_the_same_events() {
+            let received = Arc::new(Mutex::new(Vec::new()));
+            let shared = received.clone();
+            let mut robot = MinerRobot::builder()
+                .on_goal_event(move |event| shared.lock().unwrap().push((event.tick, event.kind.clone())))
+                .build()
+                .unwrap();
+            let receiver = robot.subscribe_goal_events();
+            robot.tick = 4;
+            robot.set_goal_by_content(GoalType::GetItems, Content::Rock(1), 1);
+            robot.update_goal(GoalType::GetItems, Some(Content::Rock(0)), 1);
+
+            let from_channel: Vec<(usize, GoalEventKind)> = receiver.try_iter().map(|event| (event.tick, event.kind)).collect();
+            assert_eq!(*received.lock().unwrap(), from_channel);
+            assert_eq!(from_channel.len(), 3);
+            assert!(from_channel.iter().all(|(tick, _)| *tick == 4));
+        }
+
+        #[test]
+        fn dropped_receivers_are_removed() {
+            let mut robot = MinerRobot::new();
+            drop(robot.subscribe_goal_events());
+            let receiver = robot.subscribe_goal_events();
+            robot.set_goal_by_content(GoalType::GetItems, Content::Rock(1), 1);
+            assert_eq!(robot.goal_senders.len(), 1);
+            assert_eq!(kinds(&receiver), vec![GoalEventKind::Created]);
+        }
+    }
+}
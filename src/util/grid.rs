@@ -0,0 +1,319 @@
+pub mod grid {
+    // robotics lib
+    use robotics_lib::interface::Direction;
+
+    // other
+    use serde::{Deserialize, Serialize};
+
+    /// The coordinates of a tile
+    #[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
+    pub struct Pos {
+        pub row: usize,
+        pub col: usize
+    }
+
+    impl Pos {
+        /// Creates the coordinates of a tile
+        ///
+        /// # Arguments
+        ///
+        /// * `row` - the row of the tile
+        /// * `col` - the column of the tile
+        pub fn new(row: usize, col: usize) -> Self {
+            Self { row, col }
+        }
+        /// Returns the coordinates of the adjacent tile in the given direction
+        ///
+        /// # Arguments
+        ///
+        /// * `direction` - the direction of the step
+        ///
+        /// # Returns
+        ///
+        /// An option of Pos, None if the step goes past the first row or column
+        ///
+        /// # Notes
+        ///
+        /// The upper bounds are not checked here, since they depend on the grid
+        pub fn step(&self, direction: &Direction) -> Option<Pos> {
+            match direction {
+                Direction::Up => self.row.checked_sub(1).map(|row| Pos::new(row, self.col)),
+                Direction::Down => self.row.checked_add(1).map(|row| Pos::new(row, self.col)),
+                Direction::Left => self.col.checked_sub(1).map(|col| Pos::new(self.row, col)),
+                Direction::Right => self.col.checked_add(1).map(|col| Pos::new(self.row, col))
+            }
+        }
+        /// Returns the direction of an adjacent tile
+        ///
+        /// # Arguments
+        ///
+        /// * `other` - the coordinates of the other tile
+        ///
+        /// # Returns
+        ///
+        /// An option of Direction, None if the tile is not adjacent
+        pub fn direction_to(&self, other: Pos) -> Option<Direction> {
+            Pos::directions().into_iter().find(|direction| self.step(direction) == Some(other))
+        }
+        /// Returns the Manhattan distance between two tiles
+        ///
+        /// # Arguments
+        ///
+        /// * `other` - the coordinates of the other tile
+        pub fn distance(&self, other: Pos) -> usize {
+            self.row.abs_diff(other.row) + self.col.abs_diff(other.col)
+        }
+        /// Returns the direction to follow, one axis at a time, to get closer to the target
+        ///
+        /// # Arguments
+        ///
+        /// * `target` - the coordinates of the target
+        ///
+        /// # Returns
+        ///
+        /// An option of Direction, None if the tile is the target.
+        /// The rows are covered before the columns
+        pub fn direction_towards(&self, target: Pos) -> Option<Direction> {
+            if self.row < target.row {
+                Some(Direction::Down)
+            } else if self.row > target.row {
+                Some(Direction::Up)
+            } else if self.col < target.col {
+                Some(Direction::Right)
+            } else if self.col > target.col {
+                Some(Direction::Left)
+            } else {
+                None
+            }
+        }
+        /// Returns the four directions, in the order used to visit the neighbours
+        pub fn directions() -> [Direction; 4] {
+            [Direction::Right, Direction::Left, Direction::Down, Direction::Up]
+        }
+    }
+
+    impl From<(usize, usize)> for Pos {
+        fn from((row, col): (usize, usize)) -> Self {
+            Pos::new(row, col)
+        }
+    }
+
+    impl From<Pos> for (usize, usize) {
+        fn from(pos: Pos) -> Self {
+            (pos.row, pos.col)
+        }
+    }
+
+    /// A rectangular grid of values, indexed by Pos
+    ///
+    /// # Notes
+    ///
+    /// The values are stored row by row, every access is bounds checked, so an empty grid never panics
+    #[derive(Debug, Clone, PartialEq)]
+    pub struct Grid<T> {
+        rows: usize,
+        cols: usize,
+        cells: Vec<T>
+    }
+
+    // implemented by hand, so that an empty grid doesn't need T to implement Default
+    impl<T> Default for Grid<T> {
+        fn default() -> Self {
+            Self {
+                rows: 0,
+                cols: 0,
+                cells: Vec::new()
+            }
+        }
+    }
+
+    impl<T> Grid<T> {
+        /// Creates a grid filled with the same value
+        ///
+        /// # Arguments
+        ///
+        /// * `rows` - the amount of rows
+        /// * `cols` - the amount of columns
+        /// * `value` - the value of every cell
+        pub fn new(rows: usize, cols: usize, value: T) -> Self where T: Clone {
+            Self {
+                rows,
+                cols,
+                cells: vec![value; rows * cols]
+            }
+        }
+        /// Creates a grid from its rows
+        ///
+        /// # Arguments
+        ///
+        /// * `rows` - the rows of the grid
+        ///
+        /// # Returns
+        ///
+        /// An option of Grid, None if the rows don't have the same length
+        pub fn from_rows(rows: Vec<Vec<T>>) -> Option<Self> {
+            let cols = rows.first().map_or(0, |row| row.len());
+            if rows.iter().any(|row| row.len() != cols) {
+                return None;
+            }
+            Some(Self {
+                rows: rows.len(),
+                cols,
+                cells: rows.into_iter().flatten().collect()
+            })
+        }
+        /// Returns the amount of rows
+        pub fn rows(&self) -> usize {
+            self.rows
+        }
+        /// Returns the amount of columns
+        pub fn cols(&self) -> usize {
+            self.cols
+        }
+        /// Checks if the grid has no cells
+        pub fn is_empty(&self) -> bool {
+            self.cells.is_empty()
+        }
+        /// Checks if the coordinates are inside the grid
+        pub fn contains(&self, pos: Pos) -> bool {
+            pos.row < self.rows && pos.col < self.cols
+        }
+        /// Returns the value at the given coordinates, None if they are out of bounds
+        pub fn get(&self, pos: Pos) -> Option<&T> {
+            if self.contains(pos) {
+                self.cells.get(pos.row * self.cols + pos.col)
+            } else {
+                None
+            }
+        }
+        /// Returns the mutable value at the given coordinates, None if they are out of bounds
+        pub fn get_mut(&mut self, pos: Pos) -> Option<&mut T> {
+            if self.contains(pos) {
+                self.cells.get_mut(pos.row * self.cols + pos.col)
+            } else {
+                None
+            }
+        }
+        /// Replaces the value at the given coordinates
+        ///
+        /// # Returns
+        ///
+        /// A bool stating whether the coordinates are inside the grid or not
+        pub fn set(&mut self, pos: Pos, value: T) -> bool {
+            match self.get_mut(pos) {
+                Some(cell) => {
+                    *cell = value;
+                    true
+                },
+                None => false
+            }
+        }
+        /// Returns the adjacent tile in the given direction, None if it is outside the grid
+        pub fn step(&self, pos: Pos, direction: &Direction) -> Option<Pos> {
+            pos.step(direction).filter(|next| self.contains(*next))
+        }
+        /// Returns the adjacent tiles that are inside the grid, with the direction to reach them
+        pub fn neighbours(&self, pos: Pos) -> impl Iterator<Item = (Direction, Pos)> + '_ {
+            Pos::directions().into_iter().filter_map(move |direction| {
+                self.step(pos, &direction).map(|next| (direction, next))
+            })
+        }
+        /// Returns the coordinates and the values of all the cells, row by row
+        pub fn iter(&self) -> impl Iterator<Item = (Pos, &T)> + '_ {
+            self.cells.iter().enumerate().map(move |(i, value)| (Pos::new(i / self.cols, i % self.cols), value))
+        }
+        /// Returns the rows of the grid
+        pub fn iter_rows(&self) -> impl Iterator<Item = &[T]> + '_ {
+            // chunks needs a size greater than 0, an empty grid has no rows anyway
+            self.cells.chunks(self.cols.max(1))
+        }
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use super::*;
+
+        #[test]
+        fn step_stops_at_the_first_row_and_column() {
+            let origin = Pos::new(0, 0);
+            assert_eq!(origin.step(&Direction::Up), None);
+            assert_eq!(origin.step(&Direction::Left), None);
+            assert_eq!(origin.step(&Direction::Down), Some(Pos::new(1, 0)));
+            assert_eq!(origin.step(&Direction::Right), Some(Pos::new(0, 1)));
+        }
+
+        #[test]
+        fn direction_to_only_finds_adjacent_tiles() {
+            let pos = Pos::new(2, 2);
+            assert!(matches!(pos.direction_to(Pos::new(1, 2)), Some(Direction::Up)));
+            assert!(matches!(pos.direction_to(Pos::new(3, 2)), Some(Direction::Down)));
+            assert!(matches!(pos.direction_to(Pos::new(2, 1)), Some(Direction::Left)));
+            assert!(matches!(pos.direction_to(Pos::new(2, 3)), Some(Direction::Right)));
+            assert!(pos.direction_to(pos).is_none());
+            assert!(pos.direction_to(Pos::new(3, 3)).is_none());
+            assert!(pos.direction_to(Pos::new(4, 2)).is_none());
+        }
+
+        #[test]
+        fn direction_towards_covers_the_rows_first() {
+            let pos = Pos::new(2, 2);
+            assert!(matches!(pos.direction_towards(Pos::new(0, 5)), Some(Direction::Up)));
+            assert!(matches!(pos.direction_towards(Pos::new(2, 0)), Some(Direction::Left)));
+            assert!(pos.direction_towards(pos).is_none());
+            assert_eq!(pos.distance(Pos::new(0, 5)), 5);
+        }
+
+        #[test]
+        fn from_rows_rejects_jagged_rows() {
+            assert!(Grid::from_rows(vec![vec![1, 2], vec![3]]).is_none());
+
+            let grid = Grid::from_rows(vec![vec![1, 2], vec![3, 4], vec![5, 6]]).unwrap();
+            assert_eq!((grid.rows(), grid.cols()), (3, 2));
+            assert_eq!(grid.get(Pos::new(2, 1)), Some(&6));
+            assert_eq!(grid.get(Pos::new(1, 2)), None);
+        }
+
+        #[test]
+        fn from_rows_accepts_empty_input() {
+            let grid: Grid<u8> = Grid::from_rows(Vec::new()).unwrap();
+            assert!(grid.is_empty());
+            assert_eq!((grid.rows(), grid.cols()), (0, 0));
+
+            let grid: Grid<u8> = Grid::from_rows(vec![Vec::new(), Vec::new()]).unwrap();
+            assert!(grid.is_empty());
+            assert_eq!(grid.get(Pos::new(0, 0)), None);
+        }
+
+        #[test]
+        fn neighbours_stay_inside_the_grid() {
+            let grid = Grid::new(3, 4, 0);
+            let corner: Vec<Pos> = grid.neighbours(Pos::new(0, 0)).map(|(_, pos)| pos).collect();
+            assert_eq!(corner, vec![Pos::new(0, 1), Pos::new(1, 0)]);
+
+            let corner: Vec<Pos> = grid.neighbours(Pos::new(2, 3)).map(|(_, pos)| pos).collect();
+            assert_eq!(corner, vec![Pos::new(2, 2), Pos::new(1, 3)]);
+
+            assert_eq!(grid.neighbours(Pos::new(1, 1)).count(), 4);
+            assert_eq!(grid.neighbours(Pos::new(5, 5)).count(), 0);
+        }
+
+        #[test]
+        fn set_ignores_out_of_bounds_coordinates() {
+            let mut grid = Grid::new(2, 2, false);
+            assert!(grid.set(Pos::new(1, 1), true));
+            assert!(!grid.set(Pos::new(2, 0), true));
+            assert_eq!(grid.iter().filter(|(_, value)| **value).map(|(pos, _)| pos).collect::<Vec<_>>(), vec![Pos::new(1, 1)]);
+        }
+
+        #[test]
+        fn iter_rows_of_an_empty_grid_is_empty() {
+            let grid: Grid<u8> = Grid::default();
+            assert_eq!(grid.iter_rows().count(), 0);
+            assert_eq!(grid.iter().count(), 0);
+
+            let grid = Grid::new(2, 3, 7);
+            let rows: Vec<&[i32]> = grid.iter_rows().collect();
+            assert_eq!(rows, vec![&[7, 7, 7][..], &[7, 7, 7][..]]);
+        }
+    }
+}
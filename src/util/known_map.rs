@@ -0,0 +1,148 @@
+pub mod known_map {
+    // MinerRobot
+    use crate::{ContentIndex, Grid, Pos};
+
+    // robotics lib
+    use robotics_lib::interface::robot_map;
+    use robotics_lib::world::tile::{Content, Tile, TileType};
+    use robotics_lib::world::World;
+
+    /// The map known by the robot, refreshed once per tick and from the robotics_lib events
+    ///
+    /// # Notes
+    ///
+    /// The unknown tiles are kept as None, and as DeepWater in the walkable view of the map,
+    /// so that the planning functions never walk on a tile the robot hasn't seen.
+    /// The ContentIndex is kept up to date together with the tiles
+    #[derive(Debug, Clone, Default)]
+    pub struct KnownMap {
+        options: Vec<Vec<Option<Tile>>>,
+        tiles: Grid<Tile>,
+        content_index: ContentIndex,
+        discovered_tiles: usize,
+        version: usize
+    }
+
+    /// The tile used in place of the unknown ones
+    fn unknown_tile() -> Tile {
+        Tile {
+            tile_type: TileType::DeepWater,
+            content: Content::None,
+            elevation: 0
+        }
+    }
+
+    impl KnownMap {
+        /// Creates the known map from the map of robotics_lib
+        ///
+        /// # Arguments
+        ///
+        /// * `map` - the known world, where None is an unknown tile
+        ///
+        /// # Notes
+        ///
+        /// A map whose rows have different lengths has no walkable tiles
+        pub fn from_options(map: Vec<Vec<Option<Tile>>>) -> Self {
+            let tiles = map.iter()
+                .map(|row| row.iter().map(|tile| tile.clone().unwrap_or_else(unknown_tile)).collect())
+                .collect();
+            let tiles = Grid::from_rows(tiles).unwrap_or_default();
+            let discovered_tiles = map.iter().map(|row| row.iter().filter(|tile| tile.is_some()).count()).sum();
+            Self {
+                content_index: ContentIndex::from_map(&map),
+                options: map,
+                tiles,
+                discovered_tiles,
+                version: 0
+            }
+        }
+        /// Reads the robot's map from the world again
+        ///
+        /// # Arguments
+        ///
+        /// * `world` - the world
+        ///
+        /// # Returns
+        ///
+        /// A bool stating whether the map changed or not
+        pub fn refresh(&mut self, world: &World) -> bool {
+            let map = match robot_map(world) {
+                Some(map) => map,
+                None => return false
+            };
+            if map == self.options {
+                return false;
+            }
+            let version = self.version + 1;
+            *self = Self::from_options(map);
+            self.version = version;
+            true
+        }
+        /// Replaces a single tile, used when an event tells that a tile changed
+        ///
+        /// # Arguments
+        ///
+        /// * `pos` - the coordinates of the tile
+        /// * `tile` - the new tile
+        pub fn update_tile(&mut self, pos: Pos, tile: Tile) {
+            let known = match self.options.get_mut(pos.row).and_then(|r| r.get_mut(pos.col)) {
+                Some(known) => known,
+                None => return
+            };
+            if known.as_ref() == Some(&tile) {
+                return;
+            }
+            if known.is_none() {
+                self.discovered_tiles += 1;
+            }
+            self.content_index.update(pos, known.as_ref().map(|known| &known.content), &tile.content);
+            *known = Some(tile.clone());
+            self.tiles.set(pos, tile);
+            self.version += 1;
+        }
+        /// Returns the known world, where None is an unknown tile
+        pub fn get_options(&self) -> &Vec<Vec<Option<Tile>>> {
+            &self.options
+        }
+        /// Returns the known world, where the unknown tiles are DeepWater
+        pub fn get_tiles(&self) -> &Grid<Tile> {
+            &self.tiles
+        }
+        /// Returns the tile at the given coordinates, None if it is unknown or out of bounds
+        pub fn get(&self, pos: Pos) -> Option<&Tile> {
+            self.options.get(pos.row).and_then(|r| r.get(pos.col)).and_then(|tile| tile.as_ref())
+        }
+        /// Returns the index of the known tiles by kind of content
+        pub fn get_content_index(&self) -> &ContentIndex {
+            &self.content_index
+        }
+        /// Returns the amount of rows
+        pub fn rows(&self) -> usize {
+            self.tiles.rows()
+        }
+        /// Returns the amount of columns
+        pub fn cols(&self) -> usize {
+            self.tiles.cols()
+        }
+        /// Checks if the coordinates are inside the map
+        pub fn contains(&self, pos: Pos) -> bool {
+            self.tiles.contains(pos)
+        }
+        /// Returns the amount of discovered tiles
+        pub fn get_discovered_tiles(&self) -> usize {
+            self.discovered_tiles
+        }
+        /// Returns a counter that increases every time the map changes
+        pub fn get_version(&self) -> usize {
+            self.version
+        }
+        /// Returns the map in the format used by the Lssf tool, where the unknown tiles are DeepWater
+        ///
+        /// # Notes
+        ///
+        /// This is a copy of the map, so it should only be built when the version changes
+        pub fn to_lssf_map(&self) -> Vec<Vec<Option<Tile>>> {
+            self.tiles.iter_rows().map(|row| row.iter().cloned().map(Some).collect()).collect()
+        }
+    }
+}
@@ -0,0 +1,180 @@
+pub mod ledger {
+    // MinerRobot
+    use crate::MinerRobot;
+
+    // robotics lib
+    use robotics_lib::interface::{destroy, go, put, Direction};
+    use robotics_lib::utils::LibError;
+    use robotics_lib::world::tile::Content;
+    use robotics_lib::world::World;
+
+    // other
+    use serde::{Deserialize, Serialize};
+
+    /// The kind of action that used energy
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+    pub enum ActionKind {
+        Go,
+        Destroy,
+        Put,
+        Discover,
+        Tool
+    }
+
+    /// What the robot was trying to do when it used energy
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+    pub enum Purpose {
+        Collecting,
+        Paving,
+        Scanning,
+        Exploring,
+        Recharging
+    }
+
+    /// A single action recorded in the ledger
+    #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+    pub struct LedgerEntry {
+        pub tick: usize,
+        pub action: ActionKind,
+        pub purpose: Purpose,
+        pub energy_before: usize,
+        pub energy_after: usize
+    }
+
+    /// The energy used by all the actions of a kind done for a purpose
+    #[derive(Debug, Clone, PartialEq)]
+    pub struct LedgerSummary {
+        pub action: ActionKind,
+        pub purpose: Purpose,
+        pub count: usize,
+        pub energy_spent: usize
+    }
+
+    /// Keeps track of the energy used by every action of the robot
+    #[derive(Debug, Clone, Default)]
+    pub struct EnergyLedger {
+        entries: Vec<LedgerEntry>,
+        refilled: usize
+    }
+
+    impl LedgerEntry {
+        /// Returns the energy used by the action
+        pub fn energy_spent(&self) -> usize {
+            self.energy_before.saturating_sub(self.energy_after)
+        }
+    }
+
+    impl EnergyLedger {
+        /// Adds an entry to the ledger
+        pub fn record(&mut self, entry: LedgerEntry) {
+            self.entries.push(entry);
+        }
+        /// Adds the energy given back by a recharge
+        pub fn record_refill(&mut self, energy: usize) {
+            self.refilled += energy;
+        }
+        /// Returns the energy given back by all the recharges
+        pub fn get_total_refilled(&self) -> usize {
+            self.refilled
+        }
+        /// Returns all the recorded entries
+        pub fn get_entries(&self) -> &Vec<LedgerEntry> {
+            &self.entries
+        }
+        /// Returns the energy used by all the recorded actions
+        pub fn get_total_spent(&self) -> usize {
+            self.entries.iter().map(|e| e.energy_spent()).sum()
+        }
+        /// Groups the entries by action and purpose
+        ///
+        /// # Returns
+        ///
+        /// A vector of summaries, from the one that used the most energy
+        pub fn get_summary(&self) -> Vec<LedgerSummary> {
+            let mut summary: Vec<LedgerSummary> = Vec::new();
+            for entry in &self.entries {
+                match summary.iter_mut().find(|s| s.action == entry.action && s.purpose == entry.purpose) {
+                    Some(s) => {
+                        s.count += 1;
+                        s.energy_spent += entry.energy_spent();
+                    }
+                    None => summary.push(LedgerSummary {
+                        action: entry.action,
+                        purpose: entry.purpose,
+                        count: 1,
+                        energy_spent: entry.energy_spent()
+                    })
+                }
+            }
+            summary.sort_by(|a, b| b.energy_spent.cmp(&a.energy_spent));
+            summary
+        }
+    }
+
+    impl MinerRobot {
+        /// Runs an action and records the energy it used in the ledger
+        ///
+        /// # Arguments
+        ///
+        /// * `action` - the kind of action
+        /// * `purpose` - what the action is done for
+        /// * `f` - the action
+        ///
+        /// # Returns
+        ///
+        /// The value returned by the action
+        pub fn metered<T>(&mut self, action: ActionKind, purpose: Purpose, f: impl FnOnce(&mut Self) -> T) -> T {
+            let energy_before = self.robot.energy.get_energy_level();
+            let result = f(self);
+            let entry = LedgerEntry {
+                tick: self.tick,
+                action,
+                purpose,
+                energy_before,
+                energy_after: self.robot.energy.get_energy_level()
+            };
+            self.energy_ledger.record(entry);
+            result
+        }
+        /// Moves the robot and records the energy used
+        ///
+        /// # Arguments
+        ///
+        /// * `world` - the world
+        /// * `direction` - the direction the robot moves to
+        /// * `purpose` - what the robot moves for
+        pub fn metered_go(&mut self, world: &mut World, direction: Direction, purpose: Purpose) -> Result<(), LibError> {
+            self.metered(ActionKind::Go, purpose, |robot| go(robot, world, direction).map(|_| ()))
+        }
+        /// Destroys the content in the given direction and records the energy used
+        ///
+        /// # Arguments
+        ///
+        /// * `world` - the world
+        /// * `direction` - the direction of the content
+        /// * `purpose` - what the content is destroyed for
+        ///
+        /// # Returns
+        ///
+        /// The quantity of content that got destroyed
+        pub fn metered_destroy(&mut self, world: &mut World, direction: Direction, purpose: Purpose) -> Result<usize, LibError> {
+            self.metered(ActionKind::Destroy, purpose, |robot| destroy(robot, world, direction))
+        }
+        /// Puts content in the given direction and records the energy used
+        ///
+        /// # Arguments
+        ///
+        /// * `world` - the world
+        /// * `content` - the content to put
+        /// * `quantity` - the quantity of content to put
+        /// * `direction` - the direction of the tile
+        /// * `purpose` - what the content is put for
+        ///
+        /// # Returns
+        ///
+        /// The quantity of content that got placed
+        pub fn metered_put(&mut self, world: &mut World, content: Content, quantity: usize, direction: Direction, purpose: Purpose) -> Result<usize, LibError> {
+            self.metered(ActionKind::Put, purpose, |robot| put(robot, world, content, quantity, direction))
+        }
+    }
+}
@@ -0,0 +1,150 @@
+pub mod logger {
+    use std::fs::{File, OpenOptions};
+    use std::io::{BufWriter, Write};
+    use std::path::Path;
+    use std::sync::Mutex;
+    use std::time::{SystemTime, UNIX_EPOCH};
+
+    // MinerRobot
+    use crate::{ConfigError, MinerConfig};
+
+    // other
+    use colored::Colorize;
+    use log::{Level, LevelFilter, Log, Metadata, Record};
+    use serde::Serialize;
+
+    /// A line of the JSON log file
+    #[derive(Serialize)]
+    struct LogLine<'a> {
+        timestamp_ms: u128,
+        level: &'a str,
+        target: &'a str,
+        message: String
+    }
+
+    /// Logger of the crate, it writes to the terminal and, optionally, JSON lines to a file
+    ///
+    /// # Notes
+    ///
+    /// The robot logs with these targets: movement, bridge, discovery, energy, goals, state, actions, events, map and gui.
+    /// The map target prints the known world every tick, so it only logs at the debug level
+    pub struct MinerLogger {
+        level: LevelFilter,
+        terminal: bool,
+        file: Option<Mutex<BufWriter<File>>>
+    }
+
+    impl MinerLogger {
+        /// Creates a logger that writes to the terminal
+        ///
+        /// # Arguments
+        ///
+        /// * `level` - the most verbose level that gets logged
+        pub fn new(level: LevelFilter) -> Self {
+            Self {
+                level,
+                terminal: true,
+                file: None
+            }
+        }
+        /// Creates the logger described by the configuration
+        ///
+        /// # Arguments
+        ///
+        /// * `config` - the configuration, which holds the level, the file and whether to write to the terminal
+        ///
+        /// # Returns
+        ///
+        /// The logger, or a ConfigError if the file cannot be opened
+        pub fn from_config(config: &MinerConfig) -> Result<Self, ConfigError> {
+            let mut logger = Self::new(config.get_log_level()).terminal(config.log_to_terminal);
+            if let Some(path) = &config.log_file {
+                logger = logger.with_file(path)?;
+            }
+            Ok(logger)
+        }
+        /// Sets whether the logger writes to the terminal
+        pub fn terminal(mut self, terminal: bool) -> Self {
+            self.terminal = terminal;
+            self
+        }
+        /// Appends the log lines as JSON to the given file
+        ///
+        /// # Arguments
+        ///
+        /// * `path` - the file, it gets created if it doesn't exist
+        ///
+        /// # Returns
+        ///
+        /// The logger, or a ConfigError if the file cannot be opened
+        pub fn with_file(mut self, path: impl AsRef<Path>) -> Result<Self, ConfigError> {
+            let file = OpenOptions::new().create(true).append(true).open(path).map_err(ConfigError::Io)?;
+            self.file = Some(Mutex::new(BufWriter::new(file)));
+            Ok(self)
+        }
+        /// Sets self as the logger of the program
+        ///
+        /// # Returns
+        ///
+        /// A ConfigError if a logger has already been set
+        pub fn init(self) -> Result<(), ConfigError> {
+            let level = self.level;
+            log::set_boxed_logger(Box::new(self)).map_err(|e| ConfigError::Invalid(e.to_string()))?;
+            log::set_max_level(level);
+            Ok(())
+        }
+    }
+
+    impl Log for MinerLogger {
+        fn enabled(&self, metadata: &Metadata) -> bool {
+            metadata.level() <= self.level
+        }
+        fn log(&self, record: &Record) {
+            if !self.enabled(record.metadata()) {
+                return;
+            }
+            if self.terminal {
+                let level = match record.level() {
+                    Level::Error => "ERROR".red(),
+                    Level::Warn => "WARN".yellow(),
+                    Level::Info => "INFO".green(),
+                    Level::Debug => "DEBUG".cyan(),
+                    Level::Trace => "TRACE".normal()
+                };
+                println!("[{} {}] {}", level, record.target(), record.args());
+            }
+            if let Some(file) = &self.file {
+                let line = LogLine {
+                    timestamp_ms: SystemTime::now().duration_since(UNIX_EPOCH).map(|d| d.as_millis()).unwrap_or(0),
+                    level: record.level().as_str(),
+                    target: record.target(),
+                    message: record.args().to_string()
+                };
+                // flushed after every line, the logger is never dropped so its buffer would be lost at the end of the run
+                if let (Ok(mut writer), Ok(json)) = (file.lock(), serde_json::to_string(&line)) {
+                    let _ = writeln!(writer, "{}", json).and_then(|_| writer.flush());
+                }
+            }
+        }
+        fn flush(&self) {
+            if let Some(file) = &self.file {
+                if let Ok(mut writer) = file.lock() {
+                    let _ = writer.flush();
+                }
+            }
+        }
+    }
+
+    /// Sets the logger described by the configuration as the logger of the program
+    ///
+    /// # Arguments
+    ///
+    /// * `config` - the configuration
+    ///
+    /// # Returns
+    ///
+    /// A ConfigError if the file cannot be opened or if a logger has already been set
+    pub fn init_logger(config: &MinerConfig) -> Result<(), ConfigError> {
+        MinerLogger::from_config(config)?.init()
+    }
+}
@@ -1,8 +1,27 @@
+pub mod aggregator;
+pub mod builder;
+pub mod config;
+pub mod content;
+pub mod coverage;
 pub mod debug;
+pub mod error;
 pub mod goal;
+pub mod goal_events;
+pub mod grid;
 pub mod pathfinding;
+pub mod recharge;
+pub mod recorder;
+pub mod snapshot;
+pub mod state;
+pub mod strategy;
+pub mod summary;
+pub mod watchdog;
 pub mod world;
 pub mod island;
+pub mod known_map;
+pub mod ledger;
+pub mod logger;
 pub mod bridge;
 pub mod discovery;
+pub mod exploration;
 pub mod movement;
\ No newline at end of file
@@ -1,48 +1,81 @@
 pub mod movement {
     // MinerRobot
-    use crate::MinerRobot;
+    use crate::{ActionKind, KnownMap, MinerError, MinerRobot, Pos, Purpose};
 
     // robotics lib
-    use robotics_lib::interface::{Direction, go};
-    use robotics_lib::world::tile::{Tile, TileType};
+    use robotics_lib::world::tile::TileType;
     use robotics_lib::world::World;
+
+    // tools
+    use bob_lib::tracker::GoalType;
     impl MinerRobot {
         /// Moves the robot to the given coordinates
         ///
         /// # Arguments
         ///
         /// * `world` - the world
-        /// * `map` - the known world
-        /// * `coordinates` - the target coordinates
-        pub fn move_to_coords(&mut self, world: &mut World, map: &Vec<Vec<Tile>>, coordinates: (i32,i32)) {
-            let (row,col) = coordinates;
-
+        /// * `target` - the target coordinates
+        /// * `purpose` - what the robot moves for, recorded in the energy ledger
+        ///
+        /// # Returns
+        ///
+        /// A bool stating whether the robot reached the target or not, it doesn't when it has to wait for energy.
+        /// A MinerError if there is no path to the target or if a move fails
+        ///
+        /// # Notes
+        ///
+        /// Reaching the target coordinates counts as progress for the ReachCoordinates goals.
+        /// Every step is checked against the KnownMap, which the Moved events keep up to date
+        pub fn move_to_coords(&mut self, world: &mut World, target: Pos, purpose: Purpose) -> Result<bool, MinerError> {
             // retrieving the action vector, which will indicate the robot the directions to go to
-            let action_vec = match self.lssf.get_action_vec(row as usize,col as usize){
+            let action_vec = match self.lssf.get_action_vec(target.row, target.col){
                 Ok(vec) => vec,
                 Err(e) => {
-                    self.catch_lib_error(world,e);
-                    return ();
+                    self.catch_lib_error(world,&e);
+                    return Err(MinerError::Planning {
+                        tile: Some(target.into()),
+                        reason: format!("no path to the target: {:?}", e)
+                    });
                 }
             };
+            self.current_path = self.get_path_from_actions(&action_vec);
 
             // iterating through all the actions that will lead the robot to the target coordinates
             for action in action_vec {
-                let (robot_row, robot_col) = self.get_coordinates();
                 let direction = self.action_to_direction(&action);
 
-                // calculating the new row and column
-                let (row_offset, col_offset) = self.direction_to_offset(&direction);
-                let target_row = robot_row as i32 + row_offset;
-                let target_col = robot_col as i32 + col_offset;
-
-                // checking if the coordinates are within bounds and if the tile_type is walkable
-                if self.is_in_bounds(&map,target_row,target_col) && self.is_walkable(&map[target_row as usize][target_col as usize].tile_type) {
-                    let msg = format!("Failed to move {:?}", direction);
-                    self.manage_energy(world);
-                    go(self,world,direction.clone()).expect(msg.as_str());
+                // checking if the next tile is within bounds and if the tile_type is walkable
+                let next = self.known_map.get_tiles().step(self.get_position(), &direction);
+                let walkable = next
+                    .and_then(|next| self.known_map.get_tiles().get(next))
+                    .is_some_and(|tile| self.is_walkable(&tile.tile_type));
+                let next = match next {
+                    Some(next) if walkable => next,
+                    _ => {
+                        self.current_path.clear();
+                        return Err(MinerError::Planning {
+                            tile: Some(target.into()),
+                            reason: format!("the path leaves {:?} towards a tile that cannot be walked on", self.get_position())
+                        });
+                    }
+                };
+                if !self.manage_energy(world) {
+                    return Ok(false);
+                }
+                if let Err(e) = self.metered_go(world, direction, purpose) {
+                    self.current_path.clear();
+                    return Err(self.lib_failure(world, ActionKind::Go, next.into(), e));
                 }
             }
+            // the move is over, the path is kept only while waiting for energy
+            self.current_path.clear();
+
+            // updating the goal tracker if the robot reached the target
+            let reached = self.get_position() == target;
+            if reached {
+                self.update_goal(GoalType::ReachCoordinates, None, 1);
+            }
+            Ok(reached)
         }
         /// Checks if a tile is walkable or not
         ///
@@ -61,38 +94,18 @@ pub mod movement {
                 _ => true
             }
         }
-        /// Converts the direction into an offset
-        ///
-        /// # Arguments
-        ///
-        /// * `direction` - the direction
-        ///
-        /// # Returns
-        ///
-        /// An i32 tuple representing the offset
-        pub fn direction_to_offset(&self, direction: &Direction) -> (i32,i32){
-            match direction {
-                Direction::Up => (-1,0),
-                Direction::Left => (0,-1),
-                Direction::Down => (1,0),
-                Direction::Right => (0,1),
-            }
-        }
-        /// Checks if the row value and the column value are within the matrix bounds
+        /// Checks if the coordinates are within the map bounds
         ///
         /// # Arguments
         ///
         /// * `map` - the known world
-        /// * `row` - the row to be verified
-        /// * `col` - the column to be verified
+        /// * `pos` - the coordinates to be verified
         ///
         /// # Returns
         ///
-        /// A bool representing whether the values are in bounds or not
-        pub fn is_in_bounds(&self, map: &Vec<Vec<Tile>>, row: i32, col: i32) -> bool {
-            let rows = map.len() as i32;
-            let cols = map[0].len() as i32;
-            row >= 0 && col >= 0 && row < rows && col < cols
+        /// A bool representing whether the coordinates are in bounds or not
+        pub fn is_in_bounds(&self, map: &KnownMap, pos: Pos) -> bool {
+            map.contains(pos)
         }
     }
 }
\ No newline at end of file
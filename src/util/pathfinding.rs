@@ -1,62 +1,60 @@
 pub mod path_find {
     // MinerRobot
-    use crate::{MinerRobot, RobotState, SCAN_INCREASE};
+    use crate::{ActionKind, MinerError, MinerRobot, Pos, Purpose, QuantityFilter};
 
     // robotics lib
-    use robotics_lib::interface::{destroy, go, Direction};
+    use robotics_lib::interface::Direction;
     use robotics_lib::world::{tile::Content, World};
 
     // tools
     use sense_and_find_by_rustafariani::Action;
     use bob_lib::tracker::*;
-    use colored::Colorize;
     use OwnerSheeps_Sound_Tool::functions::destroying_sound::play_sound_mining_rock;
 
-    const RANGE: usize = 2;
-    const DIRECTION: Direction = Direction::Up;
-
     impl MinerRobot {
-        /// Moves the robot to a target tile and collects the specified Content present in that tile
+        /// Moves the robot next to the closest tile containing Content and collects it
         ///
         /// # Arguments
         ///
         /// * `world` - the world
-        /// * `content` - the content that we want to collect
+        /// * `content` - the kind of content that we want to collect
+        /// * `filter` - the filter on the quantity of the content
+        ///
+        /// # Returns
+        ///
+        /// The quantity of content collected, 0 if the robot has to wait for energy.
+        /// A MinerError if there is no reachable tile containing Content or if an action fails
         ///
         /// # Notes
         ///
-        /// If the coordinates are the same after calling the move it means that the content vector is empty.
-        /// The way that this issue is handled is by:
-        /// - increasing the distance
-        /// - setting the scanned value to false in order to call the discover once again
-        /// - getting all the content around the robot
-        /// - trying to collect rocks
-        /// we increase the distance and reset the scanned value to false in order to call the discover once again
-        pub fn move_and_collect_content(&mut self, world: &mut World, content: Content) {
+        /// The robot doesn't explore if there is no reachable tile containing Content, the Exploring state takes care of that
+        pub fn move_and_collect_content(&mut self, world: &mut World, content: Content, filter: QuantityFilter) -> Result<usize, MinerError> {
+            if self.waiting_for_energy {
+                return Ok(0);
+            }
 
             // getting the vector that contains the cost to reach tiles from the robot's coordinates
-            let vec = self.get_cost_vector_to_content(world, content);
-
-            // moving the robot on the target tile and collecting the content
-            let (row,col) = self.get_coordinates();
-            self.state = RobotState::CollectingRocks;
-            self.move_to_tile_destroy_content(world, vec);
-            let (new_row,new_col) = self.get_coordinates();
-
-            if (row,col) == (new_row,new_col) {
-                // increasing the scan of the spyglass and resetting the world_scanned value in order to scan it again
-                println!("{}", "Increased scan".green());
-                self.scan_distance += SCAN_INCREASE;
-                self.world_scanned = false;
-
-                // setting conditions to use the collect_rocks method and to end the game
-                self.collect_all(world,RANGE);
-                if self.scan_distance > (SCAN_INCREASE * 4) {
-                    self.game_is_over();
-                    println!("{:?}", self);
-                } else if self.scan_distance > (SCAN_INCREASE * 3) {
-                    self.collect_rocks_inline(world,DIRECTION);
+            let vec = self.get_cost_vector_to_content(world, &content, filter);
+            let target = match vec.first() {
+                Some((_cost, target)) => *target,
+                None => {
+                    return Err(MinerError::Planning {
+                        tile: None,
+                        reason: format!("no reachable tile contains {:?}", content)
+                    });
                 }
+            };
+
+            // moving the robot next to the target tile and collecting the content
+            if !self.move_next_to_tile(world, target, Purpose::Collecting)? {
+                return Ok(0);
+            }
+            match self.direction_to(target) {
+                Some(direction) => self.collect_content_in_direction(world, direction, content),
+                None => Err(MinerError::Planning {
+                    tile: Some(target.into()),
+                    reason: String::from("the robot did not get next to the target")
+                })
             }
         }
         /// Generates and returns the vector that associates coordinates containing Content, with the cost to reach them
@@ -64,92 +62,162 @@ pub mod path_find {
         /// # Arguments
         ///
         /// * `world` - the world
-        /// * `content` - the content that we want to look for
+        /// * `content` - the kind of content that we want to look for, its quantity is ignored
+        /// * `filter` - the filter on the quantity of the content
         ///
         /// # Returns
         ///
         /// A vector of tuples:
         /// - the first element represents the cost to reach the tile
         /// - the second element represents the coordinates of the tile
-        pub fn get_cost_vector_to_content(&mut self, world: &mut World, content: Content) -> Vec<(usize,(usize,usize))>{
-            let mut cost_vector: Vec<(usize,(usize,usize))> = Vec::new();
+        ///
+        /// Only the closest tiles are priced, as many as the content_candidates of the configuration,
+        /// so the Lssf costs are not read for every known tile. The targets that the robot couldn't reach before are skipped.
+        /// The tiles are ordered by cost per unit of content, so a bigger rock wins over a closer small one
+        pub fn get_cost_vector_to_content(&mut self, world: &mut World, content: &Content, filter: QuantityFilter) -> Vec<(usize, Pos)>{
+            let mut candidates: Vec<(usize, Pos, usize)> = Vec::new();
 
-            let map = self.get_map_option(world);
-            let (x,y) = self.get_coordinates();
+            let robot = self.get_position();
 
             // updating both map and costs
-            self.update_lssf_map_and_cost(world, &map, x, y);
-
-            // getting the vector that contains all the coordinates of tiles that contain a specific content
-            let content_vec = self.get_tiles_by_content(world,content);
-
-            // adding both cost and coordinates to the cost vector by iterating over the content vector
-            for (row,col) in content_vec {
-                match self.lssf.get_cost(row,col){
-                    Some(cost) => {
-                        if (x,y) != (row,col) {
-                            cost_vector.push((cost,(row,col)));
-                        }
-                    },
-                    None => {}
-                };
+            self.update_lssf_map_and_cost(world, robot.row, robot.col);
+
+            // shortlisting the closest tiles, the skipped ones and the robot's tile are left out before counting
+            let k = self.config.content_candidates + self.skipped_targets.len() + 1;
+            let content_vec: Vec<(Pos, usize)> = self.get_nearest_content(content, k, None, filter)
+                .into_iter()
+                .filter(|(pos, _quantity)| *pos != robot && !self.skipped_targets.contains(pos))
+                .take(self.config.content_candidates)
+                .collect();
+
+            // adding both cost and coordinates to the candidates by iterating over the content vector
+            for (pos, quantity) in content_vec {
+                if let Some(cost) = self.lssf.get_cost(pos.row, pos.col) {
+                    candidates.push((cost, pos, quantity));
+                }
             }
 
-            // we order the cost vector so that the first element is the one with the lesser cost
-            cost_vector.sort();
-            cost_vector
+            // we order the candidates so that the first element is the one with the lesser cost per unit of content
+            sort_by_cost_per_quantity(&mut candidates);
+            candidates.into_iter().map(|(cost, pos, _quantity)| (cost, pos)).collect()
         }
 
-        /// Moves towards a target tile and destroys its content
+        /// Moves the robot until it reaches a tile next to the target
         ///
         /// # Arguments
         ///
         /// * `world` - the world
-        /// * `vec` - the vector of tuples (cost(row,col))
+        /// * `target` - the target's coordinates
+        /// * `purpose` - what the robot moves for, recorded in the energy ledger
+        ///
+        /// # Returns
+        ///
+        /// A bool stating whether the robot is next to the target or not, it isn't when it has to wait for energy.
+        /// A MinerError if there is no path to the target or if a move fails
         ///
         /// # Notes
         ///
-        /// The robot moves until it reaches the tile near the target, and then it destroys the target's content
-        pub fn move_to_tile_destroy_content(&mut self, world: &mut World, vec: Vec<(usize, (usize, usize))>) {
-            let (_cost,(x,y));
-            // if the vector is not empty then we take the first element which is the one that costs less to go to
-            if vec.len() > 0 {
-                (_cost,(x,y)) = vec[0];
-            } else { // otherwise we return
-                println!("The content vector is empty");
-                return;
+        /// The last action of the path is not performed, since it would move the robot on the target tile
+        pub fn move_next_to_tile(&mut self, world: &mut World, target: Pos, purpose: Purpose) -> Result<bool, MinerError> {
+            if self.direction_to(target).is_some() {
+                return Ok(true);
             }
 
-            let action_vec = match self.lssf.get_action_vec(x,y){
+            let action_vec = match self.lssf.get_action_vec(target.row, target.col){
                 Ok(vec) => vec,
                 Err(e) => {
-                    self.catch_lib_error(world,e);
-                    return ();
+                    self.catch_lib_error(world,&e);
+                    return Err(MinerError::Planning {
+                        tile: Some(target.into()),
+                        reason: format!("no path to the target: {:?}", e)
+                    });
                 }
             };
+            self.current_path = self.get_path_from_actions(&action_vec);
 
-            for (i,action) in action_vec.iter().enumerate() {
+            for action in action_vec.iter().take(action_vec.len().saturating_sub(1)) {
                 let direction = self.action_to_direction(action);
-                // calling the destroy if the robot is facing the tile containing Content
-                if i == action_vec.len() - 1 {
-                    match destroy(self, world, direction.clone()) {
-                        Ok(quantity) => {
-                            play_sound_mining_rock();
-                            // updating the rock count and the goal tracker
-                            self.update_rock_count();
-                            self.goal_tracker.update_manual(GoalType::GetItems,Some(Content::Rock(1)),quantity);
-                        }
-                        Err(e) => {
-                            self.catch_lib_error(world,e);
-                        }
-                    } ;
+                if !self.manage_energy(world) {
+                    return Ok(false);
+                }
+                // moving the robot to Direction and returning the tile it was heading to in case of failure
+                let tile = self.get_position().step(&direction).map_or(self.get_coordinates(), Into::into);
+                if let Err(e) = self.metered_go(world, direction, purpose) {
+                    self.current_path.clear();
+                    return Err(self.lib_failure(world, ActionKind::Go, tile, e));
+                }
+            }
+            // the move is over, the path is kept only while waiting for energy
+            self.current_path.clear();
+            if self.direction_to(target).is_some() {
+                Ok(true)
+            } else {
+                Err(MinerError::Planning {
+                    tile: Some(target.into()),
+                    reason: String::from("the path did not lead next to the target")
+                })
+            }
+        }
+        /// Destroys the content in the given direction and updates the rock count and the goals
+        ///
+        /// # Arguments
+        ///
+        /// * `world` - the world
+        /// * `direction` - the direction the robot is facing
+        /// * `content` - the content that gets collected, used to update the goals
+        ///
+        /// # Returns
+        ///
+        /// The quantity of content collected, or a MinerError if the content cannot be destroyed
+        pub fn collect_content_in_direction(&mut self, world: &mut World, direction: Direction, content: Content) -> Result<usize, MinerError> {
+            let coins = self.get_backpack_quantity(&Content::Coin(0));
+            let rocks = self.get_backpack_quantity(&Content::Rock(0));
+            let tile = self.get_position().step(&direction).map_or(self.get_coordinates(), Into::into);
+            match self.metered_destroy(world, direction, Purpose::Collecting) {
+                Ok(quantity) => {
+                    play_sound_mining_rock();
+                    // updating the rock count and the goal tracker
+                    self.update_rock_count(rocks);
+                    self.update_goal(GoalType::GetItems, Some(content), quantity);
+                    self.report_coins_earned(coins);
+                    Ok(quantity)
                 }
-                // moving the robot to Direction and returning an error message in case of failure
-                let msg = format!("Failed to move {:?}", direction);
-                self.manage_energy(world);
-                go(self,world,direction.clone()).expect(msg.as_str());
+                Err(e) => Err(self.lib_failure(world, ActionKind::Destroy, tile, e))
             }
         }
+        /// Returns the direction the robot has to face to reach an adjacent tile
+        ///
+        /// # Arguments
+        ///
+        /// * `target` - the tile's coordinates
+        ///
+        /// # Returns
+        ///
+        /// An option of Direction, None if the tile is not adjacent to the robot
+        pub fn direction_to(&self, target: Pos) -> Option<Direction> {
+            self.get_position().direction_to(target)
+        }
+        /// Converts the actions of a path into the tiles the robot walks on
+        ///
+        /// # Arguments
+        ///
+        /// * `actions` - the actions, starting from the robot's coordinates
+        ///
+        /// # Returns
+        ///
+        /// The coordinates of every tile of the path, the robot's tile excluded
+        pub fn get_path_from_actions(&self, actions: &[Action]) -> Vec<Pos> {
+            let mut pos = self.get_position();
+            let mut path = Vec::with_capacity(actions.len());
+            for action in actions {
+                pos = match pos.step(&self.action_to_direction(action)) {
+                    Some(next) => next,
+                    None => break
+                };
+                path.push(pos);
+            }
+            path
+        }
         /// Converts an action into a direction
         ///
         /// # Arguments
@@ -169,4 +237,48 @@ pub mod path_find {
             }
         }
     }
+
+    /// Orders the candidate targets by the cost to reach them divided by the quantity of their content
+    ///
+    /// # Arguments
+    ///
+    /// * `candidates` - tuples of cost, coordinates and quantity
+    ///
+    /// # Notes
+    ///
+    /// The ratios are compared by cross multiplication, so no floats are involved. A quantity of 0 counts as 1,
+    /// the cost is increased by 1 so that the adjacent tiles still take their quantity into account.
+    /// The sort is stable, so the candidates with the same ratio keep their order by distance
+    fn sort_by_cost_per_quantity(candidates: &mut [(usize, Pos, usize)]) {
+        candidates.sort_by(|(cost_a, _, quantity_a), (cost_b, _, quantity_b)| {
+            ((cost_a + 1) * (*quantity_b).max(1)).cmp(&((cost_b + 1) * (*quantity_a).max(1)))
+        });
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use super::*;
+
+        #[test]
+        fn bigger_rock_at_a_similar_cost_wins() {
+            let mut candidates = vec![(4, Pos::new(0, 1), 1), (6, Pos::new(3, 3), 5)];
+            sort_by_cost_per_quantity(&mut candidates);
+            assert_eq!(candidates[0].1, Pos::new(3, 3));
+        }
+
+        #[test]
+        fn small_rock_next_to_the_robot_wins_over_a_far_big_one() {
+            let mut candidates = vec![(40, Pos::new(9, 9), 3), (1, Pos::new(0, 1), 1)];
+            sort_by_cost_per_quantity(&mut candidates);
+            assert_eq!(candidates[0].1, Pos::new(0, 1));
+        }
+
+        #[test]
+        fn equal_ratios_keep_their_order() {
+            let mut candidates = vec![(3, Pos::new(0, 2), 2), (1, Pos::new(0, 1), 1), (3, Pos::new(2, 0), 0)];
+            sort_by_cost_per_quantity(&mut candidates);
+            assert_eq!(candidates.iter().map(|(_, pos, _)| *pos).collect::<Vec<Pos>>(),
+                       vec![Pos::new(0, 2), Pos::new(0, 1), Pos::new(2, 0)]);
+        }
+    }
 }
\ No newline at end of file
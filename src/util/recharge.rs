@@ -0,0 +1,163 @@
+pub mod recharge {
+    // MinerRobot
+    use crate::{MinerRobot, Purpose};
+
+    // robotics lib
+    use robotics_lib::interface::Direction;
+    use robotics_lib::runner::Runnable;
+    use robotics_lib::world::World;
+
+    // tools
+    use rust_and_furious_dynamo::dynamo::Dynamo;
+
+    // other
+    use log::warn;
+
+    /// What happened after a recharge
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    pub enum RechargeOutcome {
+        /// The energy has been refilled, the robot can go on
+        Refilled,
+        /// The robot has to wait for the natural recharge of robotics_lib
+        Waiting,
+        /// The recharge didn't work
+        Failed
+    }
+
+    /// Decides how the robot gets its energy back
+    pub trait RechargeStrategy: Send {
+        /// Returns the name of the strategy
+        fn name(&self) -> &str;
+        /// Recharges the robot's energy
+        ///
+        /// # Arguments
+        ///
+        /// * `robot` - the robot
+        /// * `world` - the world
+        ///
+        /// # Returns
+        ///
+        /// The outcome of the recharge
+        fn recharge(&mut self, robot: &mut MinerRobot, world: &mut World) -> RechargeOutcome;
+        /// Returns whether the strategy can be used in fair-play mode, which is false for the ones that refill the energy with a tool
+        fn is_fair_play(&self) -> bool {
+            true
+        }
+    }
+
+    /// Refills the energy with the Dynamo tool
+    #[derive(Debug, Clone, Default)]
+    pub struct DynamoRecharge {
+        destroy_below: bool
+    }
+
+    /// Never refills the energy, the robot waits for the natural recharge
+    #[derive(Debug, Clone, Default)]
+    pub struct WaitRecharge;
+
+    /// Recharges the energy with a user defined function
+    pub struct CustomRecharge {
+        name: String,
+        recharge: Box<dyn FnMut(&mut MinerRobot, &mut World) -> RechargeOutcome + Send>
+    }
+
+    impl DynamoRecharge {
+        /// Creates a Dynamo recharge that doesn't touch the world
+        pub fn new() -> Self {
+            Self { destroy_below: false }
+        }
+        /// Creates a Dynamo recharge that destroys the content below the robot before refilling
+        pub fn destroying_below() -> Self {
+            Self { destroy_below: true }
+        }
+    }
+
+    impl RechargeStrategy for DynamoRecharge {
+        fn name(&self) -> &str {
+            "Dynamo"
+        }
+        fn is_fair_play(&self) -> bool {
+            false
+        }
+        fn recharge(&mut self, robot: &mut MinerRobot, world: &mut World) -> RechargeOutcome {
+            if self.destroy_below {
+                let _ = robot.metered_destroy(world, Direction::Down, Purpose::Recharging);
+            }
+            *robot.get_energy_mut() = Dynamo::update_energy();
+            RechargeOutcome::Refilled
+        }
+    }
+
+    impl RechargeStrategy for WaitRecharge {
+        fn name(&self) -> &str {
+            "Wait"
+        }
+        fn recharge(&mut self, _robot: &mut MinerRobot, _world: &mut World) -> RechargeOutcome {
+            RechargeOutcome::Waiting
+        }
+    }
+
+    impl CustomRecharge {
+        /// Creates a recharge strategy from a function
+        ///
+        /// # Arguments
+        ///
+        /// * `name` - the name of the strategy
+        /// * `recharge` - the function called every time the robot needs to recharge
+        pub fn new(name: &str, recharge: impl FnMut(&mut MinerRobot, &mut World) -> RechargeOutcome + Send + 'static) -> Self {
+            Self {
+                name: String::from(name),
+                recharge: Box::new(recharge)
+            }
+        }
+    }
+
+    impl RechargeStrategy for CustomRecharge {
+        fn name(&self) -> &str {
+            &self.name
+        }
+        fn recharge(&mut self, robot: &mut MinerRobot, world: &mut World) -> RechargeOutcome {
+            (self.recharge)(robot, world)
+        }
+    }
+
+    impl MinerRobot {
+        /// Sets the strategy used every time the robot needs to recharge
+        ///
+        /// # Arguments
+        ///
+        /// * `strategy` - the recharge strategy
+        ///
+        /// # Notes
+        ///
+        /// With the fair-play feature, a strategy that is not fair-play is ignored
+        pub fn set_recharge_strategy(&mut self, strategy: impl RechargeStrategy + 'static) {
+            if cfg!(feature = "fair-play") && !strategy.is_fair_play() {
+                warn!(target: "energy", "The {} recharge cannot be used with the fair-play feature", strategy.name());
+                return;
+            }
+            self.recharge_strategy = Some(Box::new(strategy));
+        }
+        /// Enables or disables the fair-play mode, which replaces the Dynamo refills with waiting
+        ///
+        /// # Arguments
+        ///
+        /// * `fair_play` - whether the fair-play mode is enabled
+        ///
+        /// # Notes
+        ///
+        /// With the fair-play feature the mode cannot be disabled
+        pub fn set_fair_play(&mut self, fair_play: bool) {
+            if cfg!(feature = "fair-play") && !fair_play {
+                warn!(target: "energy", "The fair-play mode cannot be disabled with the fair-play feature");
+                return;
+            }
+            self.config.fair_play = fair_play;
+            if fair_play {
+                self.set_recharge_strategy(WaitRecharge);
+            } else {
+                self.set_recharge_strategy(DynamoRecharge::new());
+            }
+        }
+    }
+}
@@ -0,0 +1,376 @@
+pub mod recorder {
+    use std::fs::{self, File};
+    use std::io::{self, BufRead, BufReader, BufWriter, Write};
+    use std::path::{Path, PathBuf};
+
+    // MinerRobot
+    use crate::{BridgeJob, LedgerEntry, MinerRobot, RobotState};
+
+    // robotics lib
+    use robotics_lib::world::tile::{Content, Tile};
+
+    // other
+    use log::warn;
+    use serde::{Deserialize, Serialize};
+
+    // directory of the worlds, the recordings are stored next to them
+    const WORLD_DIR: &str = "world";
+
+    /// The first line of a recording
+    #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+    pub struct RecordHeader {
+        pub name: String,
+        pub rows: usize,
+        pub cols: usize
+    }
+
+    /// Everything that happened during a tick
+    ///
+    /// # Notes
+    ///
+    /// Only the tiles that changed since the previous tick are stored, so the recording stays small
+    #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+    pub struct TickRecord {
+        pub tick: usize,
+        pub coordinates: (usize, usize),
+        pub energy: usize,
+        pub state: RobotState,
+        pub backpack: Vec<(Content, usize)>,
+        pub bridge_job: Option<BridgeJob>,
+        /// The actions of the tick, from the energy ledger
+        pub actions: Vec<LedgerEntry>,
+        /// The robotics_lib events of the tick
+        pub events: Vec<String>,
+        /// The tiles that got discovered or changed during the tick
+        pub tiles: Vec<(usize, usize, Tile)>
+    }
+
+    /// A line of the recording
+    #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+    pub enum RecordLine {
+        Header(RecordHeader),
+        Tick(TickRecord)
+    }
+
+    /// Writes every tick of the robot as JSON lines
+    pub struct Recorder {
+        writer: BufWriter<File>,
+        known_map: Vec<Vec<Option<Tile>>>,
+        events: Vec<String>,
+        recorded_actions: usize
+    }
+
+    /// Reads a recording and rebuilds the known map and the trajectory of the robot, tick by tick
+    #[derive(Debug, Clone)]
+    pub struct Replayer {
+        header: RecordHeader,
+        ticks: Vec<TickRecord>,
+        position: usize,
+        known_map: Vec<Vec<Option<Tile>>>,
+        trajectory: Vec<(usize, usize)>
+    }
+
+    impl Recorder {
+        /// Creates a recording
+        ///
+        /// # Arguments
+        ///
+        /// * `path` - the file of the recording, it gets overwritten if it exists
+        pub fn create(path: impl AsRef<Path>) -> io::Result<Self> {
+            if let Some(parent) = path.as_ref().parent() {
+                if !parent.as_os_str().is_empty() {
+                    fs::create_dir_all(parent)?;
+                }
+            }
+            Ok(Self {
+                writer: BufWriter::new(File::create(path)?),
+                known_map: Vec::new(),
+                events: Vec::new(),
+                recorded_actions: 0
+            })
+        }
+        /// Creates a recording in the world directory
+        ///
+        /// # Arguments
+        ///
+        /// * `name` - the name of the file, such as bridge2.jsonl
+        pub fn in_world_dir(name: &str) -> io::Result<Self> {
+            Self::create(PathBuf::from(WORLD_DIR).join(name))
+        }
+        /// Keeps an event until the end of the tick
+        pub fn record_event(&mut self, event: String) {
+            self.events.push(event);
+        }
+        /// Writes a line of the recording
+        fn write_line(&mut self, line: &RecordLine) -> io::Result<()> {
+            let json = serde_json::to_string(line).map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
+            writeln!(self.writer, "{}", json)?;
+            self.writer.flush()
+        }
+    }
+
+    impl Replayer {
+        /// Opens a recording
+        ///
+        /// # Arguments
+        ///
+        /// * `path` - the file of the recording
+        pub fn open(path: impl AsRef<Path>) -> io::Result<Self> {
+            Self::from_reader(BufReader::new(File::open(path)?))
+        }
+        /// Reads a recording
+        ///
+        /// # Arguments
+        ///
+        /// * `reader` - the JSON lines of the recording, starting with the header
+        ///
+        /// # Returns
+        ///
+        /// The replayer, positioned before the first tick
+        pub fn from_reader(reader: impl BufRead) -> io::Result<Self> {
+            let mut header = None;
+            let mut ticks = Vec::new();
+            for line in reader.lines() {
+                let line = line?;
+                if line.trim().is_empty() {
+                    continue;
+                }
+                match serde_json::from_str(&line).map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))? {
+                    RecordLine::Header(h) => header = Some(h),
+                    RecordLine::Tick(tick) => ticks.push(tick)
+                }
+            }
+            let header = header.ok_or_else(|| io::Error::new(io::ErrorKind::InvalidData, "the recording has no header"))?;
+            Ok(Self {
+                known_map: vec![vec![None; header.cols]; header.rows],
+                header,
+                ticks,
+                position: 0,
+                trajectory: Vec::new()
+            })
+        }
+        /// Applies the next tick of the recording
+        ///
+        /// # Returns
+        ///
+        /// The record of the tick, None once the recording is over
+        pub fn next_tick(&mut self) -> Option<&TickRecord> {
+            let record = self.ticks.get(self.position)?;
+            for (row, col, tile) in &record.tiles {
+                if let Some(known) = self.known_map.get_mut(*row).and_then(|r| r.get_mut(*col)) {
+                    *known = Some(tile.clone());
+                }
+            }
+            if self.trajectory.last() != Some(&record.coordinates) {
+                self.trajectory.push(record.coordinates);
+            }
+            self.position += 1;
+            Some(record)
+        }
+        /// Applies the ticks up to the given one, starting over if it has already been replayed
+        ///
+        /// # Arguments
+        ///
+        /// * `tick` - the tick to reach
+        pub fn seek(&mut self, tick: usize) {
+            if self.position > 0 && self.ticks[self.position - 1].tick > tick {
+                self.rewind();
+            }
+            while self.ticks.get(self.position).map_or(false, |record| record.tick <= tick) {
+                self.next_tick();
+            }
+        }
+        /// Goes back to the beginning of the recording
+        pub fn rewind(&mut self) {
+            self.known_map = vec![vec![None; self.header.cols]; self.header.rows];
+            self.trajectory.clear();
+            self.position = 0;
+        }
+        /// Returns the header of the recording
+        pub fn get_header(&self) -> &RecordHeader {
+            &self.header
+        }
+        /// Returns the map known by the robot at the current tick
+        pub fn get_known_map(&self) -> &Vec<Vec<Option<Tile>>> {
+            &self.known_map
+        }
+        /// Returns the tiles the robot has been on so far
+        pub fn get_trajectory(&self) -> &Vec<(usize, usize)> {
+            &self.trajectory
+        }
+        /// Returns the amount of recorded ticks
+        pub fn len(&self) -> usize {
+            self.ticks.len()
+        }
+        /// Checks if the recording has no ticks
+        pub fn is_empty(&self) -> bool {
+            self.ticks.is_empty()
+        }
+    }
+
+    impl MinerRobot {
+        /// Records the robot from now on
+        ///
+        /// # Arguments
+        ///
+        /// * `recorder` - the recorder
+        pub fn set_recorder(&mut self, recorder: Recorder) {
+            self.recorder = Some(recorder);
+        }
+        /// Writes the tick to the recording, if there is one
+        ///
+        /// # Notes
+        ///
+        /// The recording stops if the file can't be written anymore
+        pub(crate) fn record_tick(&mut self) {
+            let mut recorder = match self.recorder.take() {
+                Some(recorder) => recorder,
+                None => return
+            };
+            let map = self.known_map.get_options();
+
+            // nothing is recorded until the size of the world is known
+            if map.is_empty() {
+                self.recorder = Some(recorder);
+                return;
+            }
+
+            // the header is written once the size of the world is known
+            let mut result = Ok(());
+            if recorder.known_map.is_empty() {
+                recorder.known_map = map.iter().map(|row| vec![None; row.len()]).collect();
+                result = recorder.write_line(&RecordLine::Header(RecordHeader {
+                    name: self.name.clone(),
+                    rows: self.known_map.rows(),
+                    cols: self.known_map.cols()
+                }));
+            }
+
+            // only the tiles that changed are written
+            let mut tiles = Vec::new();
+            for (row, tiles_row) in map.iter().enumerate() {
+                for (col, tile) in tiles_row.iter().enumerate() {
+                    if let Some(tile) = tile {
+                        if recorder.known_map[row][col].as_ref() != Some(tile) {
+                            recorder.known_map[row][col] = Some(tile.clone());
+                            tiles.push((row, col, tile.clone()));
+                        }
+                    }
+                }
+            }
+
+            let entries = self.energy_ledger.get_entries();
+            let actions = entries[recorder.recorded_actions.min(entries.len())..].to_vec();
+            recorder.recorded_actions = entries.len();
+
+            let snapshot = self.get_snapshot(0);
+            let record = TickRecord {
+                tick: self.tick,
+                coordinates: snapshot.coordinates,
+                energy: snapshot.energy,
+                state: snapshot.state,
+                backpack: snapshot.backpack,
+                bridge_job: snapshot.bridge_job,
+                actions,
+                events: std::mem::take(&mut recorder.events),
+                tiles
+            };
+            result = result.and_then(|_| recorder.write_line(&RecordLine::Tick(record)));
+
+            match result {
+                Ok(()) => self.recorder = Some(recorder),
+                Err(e) => warn!(target: "state", "The recording stopped: {}", e)
+            }
+        }
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use super::*;
+        use robotics_lib::world::tile::TileType;
+
+        fn tick(tick: usize, coordinates: (usize, usize), tiles: Vec<(usize, usize, Tile)>) -> RecordLine {
+            RecordLine::Tick(TickRecord {
+                tick,
+                coordinates,
+                energy: 1000 - tick,
+                state: RobotState::Exploring,
+                backpack: Vec::new(),
+                bridge_job: None,
+                actions: Vec::new(),
+                events: vec![format!("tick {}", tick)],
+                tiles
+            })
+        }
+
+        fn tile(tile_type: TileType, content: Content) -> Tile {
+            Tile { tile_type, content, elevation: 0 }
+        }
+
+        fn recording() -> String {
+            let lines = vec![
+                RecordLine::Header(RecordHeader { name: "test".to_string(), rows: 2, cols: 3 }),
+                tick(0, (0, 0), vec![(0, 0, tile(TileType::Grass, Content::None)), (0, 1, tile(TileType::Grass, Content::Rock(2)))]),
+                tick(1, (0, 1), vec![(0, 1, tile(TileType::Grass, Content::None)), (1, 2, tile(TileType::DeepWater, Content::None))]),
+                tick(2, (0, 1), Vec::new())
+            ];
+            lines.iter().map(|line| serde_json::to_string(line).unwrap() + "\n").collect()
+        }
+
+        #[test]
+        fn replays_a_recording() {
+            let mut replayer = Replayer::from_reader(recording().as_bytes()).unwrap();
+            assert_eq!(replayer.get_header().name, "test");
+            assert_eq!(replayer.len(), 3);
+            assert_eq!(replayer.get_known_map().len(), 2);
+            assert!(replayer.get_known_map().iter().flatten().all(|tile| tile.is_none()));
+
+            assert_eq!(replayer.next_tick().map(|record| record.tick), Some(0));
+            assert_eq!(replayer.get_known_map()[0][1], Some(tile(TileType::Grass, Content::Rock(2))));
+
+            replayer.next_tick();
+            assert_eq!(replayer.get_known_map()[0][1], Some(tile(TileType::Grass, Content::None)));
+            assert_eq!(replayer.get_known_map()[1][2], Some(tile(TileType::DeepWater, Content::None)));
+            assert_eq!(replayer.get_known_map()[1][0], None);
+
+            // standing still doesn't extend the trajectory
+            let record = replayer.next_tick().cloned();
+            assert_eq!(record.map(|record| record.events), Some(vec!["tick 2".to_string()]));
+            assert_eq!(replayer.get_trajectory(), &vec![(0, 0), (0, 1)]);
+            assert!(replayer.next_tick().is_none());
+        }
+
+        #[test]
+        fn seeks_backwards() {
+            let mut replayer = Replayer::from_reader(recording().as_bytes()).unwrap();
+            replayer.seek(2);
+            replayer.seek(0);
+            assert_eq!(replayer.get_trajectory(), &vec![(0, 0)]);
+            assert_eq!(replayer.get_known_map()[0][1], Some(tile(TileType::Grass, Content::Rock(2))));
+            assert_eq!(replayer.get_known_map()[1][2], None);
+        }
+
+        #[test]
+        fn rejects_a_recording_without_header() {
+            let line = serde_json::to_string(&tick(0, (0, 0), Vec::new())).unwrap();
+            assert!(Replayer::from_reader(line.as_bytes()).is_err());
+        }
+
+        #[test]
+        fn recorder_writes_readable_lines() {
+            let path = std::env::temp_dir().join(format!("holy_crab_recorder_{}.jsonl", std::process::id()));
+            let mut recorder = Recorder::create(&path).unwrap();
+            for line in recording().lines() {
+                let line: RecordLine = serde_json::from_str(line).unwrap();
+                recorder.write_line(&line).unwrap();
+            }
+            drop(recorder);
+
+            let mut replayer = Replayer::open(&path).unwrap();
+            fs::remove_file(&path).unwrap();
+            replayer.seek(2);
+            assert_eq!(replayer.len(), 3);
+            assert_eq!(replayer.get_trajectory(), &vec![(0, 0), (0, 1)]);
+        }
+    }
+}
@@ -0,0 +1,95 @@
+pub mod snapshot {
+    // MinerRobot
+    use crate::{BridgeJob, GameOverReason, GoalRecord, MinerRobot, Pos, RobotState};
+
+    // robotics lib
+    use robotics_lib::world::tile::Content;
+
+    /// A copy of what the robot knows and does, published after every tick
+    ///
+    /// # Notes
+    ///
+    /// The GUI and the other threads read the snapshot, so they never have to lock the robot or the world
+    #[derive(Debug, Clone)]
+    pub struct RobotSnapshot {
+        pub tick: usize,
+        pub coordinates: (usize, usize),
+        pub energy: usize,
+        pub backpack: Vec<(Content, usize)>,
+        pub state: RobotState,
+        pub goals: Vec<GoalRecord>,
+        /// The tile the robot is heading to, if there is one
+        pub target: Option<Pos>,
+        /// The tiles of the last path the robot planned
+        pub path: Vec<Pos>,
+        pub bridge_job: Option<BridgeJob>,
+        pub discovered_tiles: usize,
+        /// Increased every time new tiles are discovered, so readers only redraw the map when it changes
+        pub discovered_version: usize,
+        pub game_over_reason: Option<GameOverReason>
+    }
+
+    impl Default for RobotSnapshot {
+        fn default() -> Self {
+            Self {
+                tick: 0,
+                coordinates: (0, 0),
+                energy: 0,
+                backpack: Vec::new(),
+                state: RobotState::Exploring,
+                goals: Vec::new(),
+                target: None,
+                path: Vec::new(),
+                bridge_job: None,
+                discovered_tiles: 0,
+                discovered_version: 0,
+                game_over_reason: None
+            }
+        }
+    }
+
+    impl MinerRobot {
+        /// Creates the snapshot of the robot
+        ///
+        /// # Arguments
+        ///
+        /// * `discovered_version` - the version of the discovered tiles
+        pub fn get_snapshot(&self, discovered_version: usize) -> RobotSnapshot {
+            let mut backpack: Vec<(Content, usize)> = self.robot.backpack.get_contents()
+                .iter()
+                .filter(|(_, quantity)| **quantity > 0)
+                .map(|(content, quantity)| (content.clone(), *quantity))
+                .collect();
+            backpack.sort_by(|a, b| b.1.cmp(&a.1));
+
+            RobotSnapshot {
+                tick: self.tick,
+                coordinates: self.get_coordinates(),
+                energy: self.robot.energy.get_energy_level(),
+                backpack,
+                state: self.state,
+                goals: self.goal_records.clone(),
+                target: self.resource_target.or(self.bridge_job.as_ref().map(|job| job.start)),
+                path: self.current_path.clone(),
+                bridge_job: self.bridge_job.clone(),
+                discovered_tiles: self.coverage.discovered_tiles,
+                discovered_version,
+                game_over_reason: self.game_over_reason.clone()
+            }
+        }
+        /// Shares the snapshot of the robot with the other threads
+        ///
+        /// # Notes
+        ///
+        /// If a reader panicked while holding the lock the snapshot is not published anymore, but the robot keeps running
+        pub(crate) fn publish_snapshot(&mut self) {
+            if let Ok(mut snapshot) = self.snapshot.lock() {
+                let mut version = snapshot.discovered_version;
+                if snapshot.discovered_tiles != self.coverage.discovered_tiles {
+                    version += 1;
+                }
+                *snapshot = self.get_snapshot(version);
+            }
+        }
+    }
+}
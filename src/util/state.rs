@@ -0,0 +1,378 @@
+pub mod state {
+    // MinerRobot
+    use crate::{GameOverReason, MinerRobot, Purpose, QuantityFilter, RobotState};
+
+    // robotics lib
+    use robotics_lib::world::tile::Content;
+    use robotics_lib::world::World;
+
+    // other
+    use log::{debug, info, warn};
+
+    /// A change of state of the robot
+    #[derive(Debug, Clone, PartialEq)]
+    pub struct StateTransition {
+        pub tick: usize,
+        pub from: RobotState,
+        pub to: RobotState,
+        pub reason: String
+    }
+
+    impl RobotState {
+        /// Checks if the robot can go from this state to the next one
+        ///
+        /// # Arguments
+        ///
+        /// * `next` - the next state
+        ///
+        /// # Returns
+        ///
+        /// A bool stating whether the transition is allowed or not
+        ///
+        /// # Notes
+        ///
+        /// Every state can end the game or stop to recharge, apart from Finished which is the last state
+        pub fn can_transition_to(&self, next: &RobotState) -> bool {
+            use RobotState::*;
+            match (self, next) {
+                (Finished, _) => false,
+                (_, Finished) => true,
+                (Recharging, Recharging) => false,
+                (_, Recharging) => true,
+                (Exploring, TravelingToResource | TravelingToBridgeStart | Stuck) => true,
+                (TravelingToResource, Collecting | Exploring | Stuck) => true,
+                (Collecting, Exploring | TravelingToResource | TravelingToBridgeStart | Stuck) => true,
+                (TravelingToBridgeStart, Paving | Exploring | Stuck) => true,
+                (Paving, Exploring | TravelingToBridgeStart | Stuck) => true,
+                (Recharging, _) => true,
+                (Stuck, Exploring | TravelingToResource) => true,
+                _ => false
+            }
+        }
+    }
+
+    impl MinerRobot {
+        /// Moves the robot to the next state, calling the exit and entry hooks and logging the transition
+        ///
+        /// # Arguments
+        ///
+        /// * `next` - the next state
+        /// * `reason` - why the robot changes state
+        ///
+        /// # Returns
+        ///
+        /// A bool stating whether the transition happened or not
+        pub fn transition_to(&mut self, next: RobotState, reason: &str) -> bool {
+            let previous = self.state;
+            if previous == next {
+                return false;
+            }
+            if !previous.can_transition_to(&next) {
+                warn!(target: "state", "Invalid transition {:?} -> {:?} ({})", previous, next, reason);
+                return false;
+            }
+
+            self.on_exit_state(previous, next);
+            self.state = next;
+            self.state_history.push(StateTransition {
+                tick: self.tick,
+                from: previous,
+                to: next,
+                reason: String::from(reason)
+            });
+            info!(target: "state", "{:?} -> {:?} ({})", previous, next, reason);
+            self.on_enter_state(next, previous, reason);
+            true
+        }
+        /// Performs the work of the current state, one step for each tick
+        ///
+        /// # Arguments
+        ///
+        /// * `world` - the world
+        pub fn run_state_machine(&mut self, world: &mut World) {
+            // every state but the last one stops when the energy is too low
+            let low_energy = self.waiting_for_energy || self.robot.energy.get_energy_level() < self.config.min_energy;
+            if low_energy && self.state != RobotState::Recharging && self.state != RobotState::Finished {
+                self.transition_to(RobotState::Recharging, "energy below the minimum");
+            }
+
+            match self.state {
+                RobotState::Exploring => self.exploring_step(world),
+                RobotState::TravelingToResource => self.traveling_to_resource_step(world),
+                RobotState::Collecting => self.collecting_step(world),
+                RobotState::TravelingToBridgeStart => self.traveling_to_bridge_start_step(world),
+                RobotState::Paving => self.paving_step(world),
+                RobotState::Recharging => self.recharging_step(world),
+                RobotState::Stuck => self.stuck_step(world),
+                RobotState::Finished => {}
+            }
+        }
+        /// Called when the robot leaves a state
+        ///
+        /// # Arguments
+        ///
+        /// * `state` - the state the robot leaves
+        /// * `next` - the state the robot goes to
+        ///
+        /// # Notes
+        ///
+        /// Nothing is cleared when the robot stops to recharge, since it will go back to the same state
+        fn on_exit_state(&mut self, state: RobotState, next: RobotState) {
+            if next == RobotState::Recharging {
+                return;
+            }
+            self.current_path.clear();
+            match state {
+                RobotState::Paving => self.bridge_job = None,
+                RobotState::Collecting => self.resource_target = None,
+                _ => {}
+            }
+        }
+        /// Called when the robot enters a state
+        ///
+        /// # Arguments
+        ///
+        /// * `state` - the state the robot enters
+        /// * `previous` - the state the robot comes from
+        /// * `reason` - why the robot changed state
+        fn on_enter_state(&mut self, state: RobotState, previous: RobotState, reason: &str) {
+            match state {
+                RobotState::Recharging => self.resume_state = Some(previous),
+                RobotState::TravelingToBridgeStart => {
+                    if previous != RobotState::Recharging {
+                        self.bridge_iterations = 0;
+                    }
+                },
+                RobotState::Finished => {
+                    // the reason is set by finish(), any other transition to Finished is a stop
+                    let reason = self.game_over_reason.clone().unwrap_or_else(|| GameOverReason::Stopped(String::from(reason)));
+                    self.game_is_over(reason, previous);
+                    info!(target: "state", "{:?}", self);
+                },
+                _ => {}
+            }
+        }
+        /// Scans the area, then looks for a bridge to build or a rock to collect, and explores a frontier if there is none
+        ///
+        /// # Arguments
+        ///
+        /// * `world` - the world
+        fn exploring_step(&mut self, world: &mut World) {
+            // a scan that is pending or that has not been done yet is the work of this tick
+            if self.spyglass.is_some() || !self.world_scanned {
+                if let Err(e) = self.scan_world(world, self.scan_distance) {
+                    warn!(target: "discovery", "{}", e);
+                }
+                return;
+            }
+
+            // building a bridge if the rocks are enough
+            if let Some(job) = self.calculate_bridge_job(&self.known_map) {
+                if self.rocks_collected >= job.cost {
+                    self.bridge_job = Some(job);
+                    self.transition_to(RobotState::TravelingToBridgeStart, "enough rocks to build the bridge");
+                    return;
+                }
+            }
+
+            // looking for the closest rock
+            let cost_vector = self.get_cost_vector_to_content(world, &Content::Rock(0), QuantityFilter::Any);
+            if let Some((_cost, target)) = cost_vector.first() {
+                self.resource_target = Some(*target);
+                self.transition_to(RobotState::TravelingToResource, "rock found");
+                return;
+            }
+
+            // getting all the content around the robot and exploring the frontier,
+            // the game ends only when there is no reachable frontier left
+            self.collect_all(world, self.config.range);
+            match self.explore_frontier(world) {
+                Ok(true) => {},
+                Ok(false) => {
+                    // a last attempt at collecting rocks, the game ends anyway if the machine fails
+                    if let Err(e) = self.collect_rocks_inline(world, self.config.get_direction()) {
+                        warn!(target: "actions", "{}", e);
+                    }
+                    let reason = match self.calculate_bridge_job(&self.known_map) {
+                        Some(job) if self.rocks_collected < job.cost => GameOverReason::NotEnoughRocks {
+                            needed: job.cost,
+                            collected: self.rocks_collected
+                        },
+                        _ => GameOverReason::NoReachableFrontier
+                    };
+                    self.finish(reason);
+                },
+                // the frontier is marked as explored, so the next tick picks another one
+                Err(e) => warn!(target: "discovery", "{}", e)
+            }
+        }
+        /// Moves the robot next to the resource
+        ///
+        /// # Arguments
+        ///
+        /// * `world` - the world
+        fn traveling_to_resource_step(&mut self, world: &mut World) {
+            let target = match self.resource_target {
+                Some(target) => target,
+                None => {
+                    self.transition_to(RobotState::Exploring, "no resource to reach");
+                    return;
+                }
+            };
+
+            match self.move_next_to_tile(world, target, Purpose::Collecting) {
+                Ok(true) => {
+                    self.transition_to(RobotState::Collecting, "next to the resource");
+                },
+                Ok(false) => {},
+                Err(e) => {
+                    warn!(target: "movement", "{}", e);
+                    if !self.waiting_for_energy {
+                        self.transition_to(RobotState::Stuck, "the resource cannot be reached");
+                    }
+                }
+            }
+        }
+        /// Collects the resource next to the robot
+        ///
+        /// # Arguments
+        ///
+        /// * `world` - the world
+        fn collecting_step(&mut self, world: &mut World) {
+            let direction = match self.resource_target.and_then(|target| self.direction_to(target)) {
+                Some(direction) => direction,
+                None => {
+                    self.transition_to(RobotState::TravelingToResource, "the resource is not next to the robot");
+                    return;
+                }
+            };
+
+            if let Err(e) = self.collect_content_in_direction(world, direction, Content::Rock(1)) {
+                warn!(target: "actions", "{}", e);
+            }
+            if !self.waiting_for_energy {
+                self.transition_to(RobotState::Exploring, "resource collected");
+            }
+        }
+        /// Moves the robot to the starting tile of the bridge and checks that the target did not change
+        ///
+        /// # Arguments
+        ///
+        /// * `world` - the world
+        fn traveling_to_bridge_start_step(&mut self, world: &mut World) {
+            let job = match self.bridge_job.clone() {
+                Some(job) => job,
+                None => {
+                    self.transition_to(RobotState::Exploring, "no bridge to build");
+                    return;
+                }
+            };
+
+            match self.move_to_bridge_start(world, &job) {
+                Ok(None) => {
+                    self.transition_to(RobotState::Paving, "on the starting tile of the bridge");
+                },
+                Ok(Some(_)) | Err(_) if self.waiting_for_energy => {},
+                Err(e) => {
+                    warn!(target: "bridge", "{}", e);
+                    self.transition_to(RobotState::Exploring, "the starting tile of the bridge cannot be reached");
+                },
+                Ok(Some(new_job)) => {
+                    self.bridge_iterations += 1;
+                    if self.bridge_iterations >= self.config.max_bridge_iterations {
+                        self.finish(GameOverReason::BridgeTargetKeptChanging);
+                    } else if self.rocks_collected < new_job.cost {
+                        self.transition_to(RobotState::Exploring, "not enough rocks for the new bridge");
+                    } else {
+                        self.bridge_job = Some(new_job);
+                    }
+                }
+            }
+        }
+        /// Builds the bridge, an interrupted bridge goes on from where it stopped
+        ///
+        /// # Arguments
+        ///
+        /// * `world` - the world
+        fn paving_step(&mut self, world: &mut World) {
+            let job = match self.bridge_job.clone() {
+                Some(job) => job,
+                None => {
+                    self.transition_to(RobotState::Exploring, "no bridge to build");
+                    return;
+                }
+            };
+
+            match self.build_bridge(world, &job) {
+                Ok(()) if self.waiting_for_energy => {},
+                Ok(()) => {
+                    self.transition_to(RobotState::Exploring, "bridge built");
+                },
+                Err(e) => {
+                    warn!(target: "bridge", "{}", e);
+                    if !self.waiting_for_energy {
+                        self.transition_to(RobotState::Exploring, "the bridge cannot be built");
+                    }
+                }
+            }
+        }
+        /// Recharges the robot and goes back to the previous state once the energy is enough
+        ///
+        /// # Arguments
+        ///
+        /// * `world` - the world
+        fn recharging_step(&mut self, world: &mut World) {
+            if !self.waiting_for_energy {
+                self.recharge_energy(world);
+            }
+            if self.is_waiting_for_energy() {
+                debug!(target: "energy", "Idle tick, energy: {}", self.robot.energy.get_energy_level());
+                return;
+            }
+            // a scan that ran out of budget goes on with the recharged energy
+            self.refill_scan_budget();
+            let next = self.resume_state.take().unwrap_or(RobotState::Exploring);
+            self.transition_to(next, "energy recharged");
+        }
+        /// Recovers the robot when it stops making progress
+        ///
+        /// # Arguments
+        ///
+        /// * `world` - the world
+        ///
+        /// # Notes
+        ///
+        /// The robot first gives up on the current resource, then explores another frontier,
+        /// and the run ends once the recoveries didn't lead to any progress
+        fn stuck_step(&mut self, world: &mut World) {
+            // the target that could not be reached is skipped from now on
+            if let Some(target) = self.resource_target.take() {
+                self.skipped_targets.insert(target);
+                self.transition_to(RobotState::Exploring, "skipping the unreachable resource");
+                return;
+            }
+
+            // the run ends if the recoveries didn't lead anywhere
+            if self.watchdog.get_recoveries() > self.config.max_recoveries {
+                let reason = format!("no progress after {} recoveries", self.config.max_recoveries);
+                self.finish(GameOverReason::Stuck(reason));
+                return;
+            }
+
+            // the bridge gets recalculated and the robot looks for new tiles somewhere else
+            self.bridge_job = None;
+            match self.explore_frontier(world) {
+                Ok(true) => {
+                    self.transition_to(RobotState::Exploring, "explored a new frontier");
+                },
+                Ok(false) => {
+                    self.finish(GameOverReason::NoReachableFrontier);
+                },
+                Err(e) => {
+                    warn!(target: "movement", "{}", e);
+                    self.transition_to(RobotState::Exploring, "the frontier cannot be reached");
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,122 @@
+pub mod strategy {
+    // MinerRobot
+    use crate::{MinerError, MinerRobot};
+
+    // robotics lib
+    use robotics_lib::world::World;
+
+    // other
+    use log::warn;
+
+    /// What a strategy can work with during a tick
+    pub struct StrategyContext<'a> {
+        /// The robot, with all its tools and its known world
+        pub robot: &'a mut MinerRobot,
+        /// The world
+        pub world: &'a mut World,
+        /// The current tick
+        pub tick: usize
+    }
+
+    /// Decides what the robot does every tick
+    ///
+    /// # Notes
+    ///
+    /// The goals, the coverage and the shared coordinates are updated by the robot before and after each step,
+    /// so a strategy only has to pick the next action
+    pub trait MinerStrategy: Send {
+        /// Returns the name of the strategy
+        fn name(&self) -> &str;
+        /// Does the work of a single tick
+        ///
+        /// # Arguments
+        ///
+        /// * `context` - the robot, the world and the current tick
+        ///
+        /// # Returns
+        ///
+        /// A MinerError if the action of this tick failed, the robot logs it and goes on with the next tick
+        fn step(&mut self, context: StrategyContext) -> Result<(), MinerError>;
+    }
+
+    /// Explores the world, collects rocks and builds bridges towards the closest island, using the robot's state machine
+    #[derive(Debug, Clone, Default)]
+    pub struct DefaultBridgeStrategy;
+
+    /// Decides the next action with a user defined function
+    pub struct CustomStrategy {
+        name: String,
+        step: Box<dyn FnMut(StrategyContext) -> Result<(), MinerError> + Send>
+    }
+
+    impl CustomStrategy {
+        /// Creates a strategy from a function
+        ///
+        /// # Arguments
+        ///
+        /// * `name` - the name of the strategy
+        /// * `step` - the function called every tick
+        pub fn new(name: &str, step: impl FnMut(StrategyContext) -> Result<(), MinerError> + Send + 'static) -> Self {
+            Self {
+                name: String::from(name),
+                step: Box::new(step)
+            }
+        }
+    }
+
+    impl MinerStrategy for DefaultBridgeStrategy {
+        fn name(&self) -> &str {
+            "Default bridge"
+        }
+        fn step(&mut self, context: StrategyContext) -> Result<(), MinerError> {
+            context.robot.run_state_machine(context.world);
+            Ok(())
+        }
+    }
+
+    impl MinerStrategy for CustomStrategy {
+        fn name(&self) -> &str {
+            &self.name
+        }
+        fn step(&mut self, context: StrategyContext) -> Result<(), MinerError> {
+            (self.step)(context)
+        }
+    }
+
+    impl MinerRobot {
+        /// Sets the strategy that decides what the robot does every tick
+        ///
+        /// # Arguments
+        ///
+        /// * `strategy` - the new strategy
+        pub fn set_strategy(&mut self, strategy: impl MinerStrategy + 'static) {
+            self.strategy = Some(Box::new(strategy));
+        }
+        /// Returns the name of the current strategy
+        pub fn get_strategy_name(&self) -> Option<&str> {
+            self.strategy.as_ref().map(|strategy| strategy.name())
+        }
+        /// Runs a step of the current strategy
+        ///
+        /// # Arguments
+        ///
+        /// * `world` - the world
+        ///
+        /// # Notes
+        ///
+        /// The strategy is taken out of the robot while it runs, a strategy set during the step replaces it
+        pub(crate) fn run_strategy(&mut self, world: &mut World) {
+            let mut strategy = match self.strategy.take() {
+                Some(strategy) => strategy,
+                None => return
+            };
+            let tick = self.tick;
+            if let Err(e) = strategy.step(StrategyContext { robot: self, world, tick }) {
+                warn!(target: "state", "{} strategy: {}", strategy.name(), e);
+            }
+            if self.strategy.is_none() {
+                self.strategy = Some(strategy);
+            }
+        }
+    }
+}
@@ -0,0 +1,159 @@
+pub mod summary {
+    use std::fmt::{Display, Formatter};
+
+    // MinerRobot
+    use crate::{MinerRobot, RobotState};
+
+    // other
+    use log::info;
+    use serde::{Deserialize, Serialize};
+
+    /// Why the game ended
+    #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+    pub enum GameOverReason {
+        /// The bridge points changed too many times while the robot was moving to the starting tile
+        BridgeTargetKeptChanging,
+        /// Every reachable frontier has been explored, the scan distance can't reveal anything else
+        NoReachableFrontier,
+        /// There is an island to connect but the robot couldn't find enough rocks
+        NotEnoughRocks {
+            needed: usize,
+            collected: usize
+        },
+        /// The robot stopped making progress
+        Stuck(String),
+        /// The strategy ended the game
+        Stopped(String)
+    }
+
+    impl Display for GameOverReason {
+        fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+            match self {
+                GameOverReason::BridgeTargetKeptChanging => write!(f, "the bridge target keeps on changing"),
+                GameOverReason::NoReachableFrontier => write!(f, "no reachable frontier left"),
+                GameOverReason::NotEnoughRocks { needed, collected } => write!(f, "the bridge needs {} rocks, only {} were collected", needed, collected),
+                GameOverReason::Stuck(reason) => write!(f, "the robot is stuck: {}", reason),
+                GameOverReason::Stopped(reason) => write!(f, "stopped: {}", reason)
+            }
+        }
+    }
+
+    /// The results of a run, created when the game ends
+    #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+    pub struct RunSummary {
+        pub name: String,
+        pub reason: GameOverReason,
+        pub ticks: usize,
+        pub energy_used: usize,
+        pub energy_refilled: usize,
+        pub rocks_mined: usize,
+        pub bridges_built: usize,
+        pub tiles_discovered: usize,
+        pub goals_completed: usize,
+        /// The state the robot was in when the game ended, before moving to Finished
+        pub final_state: RobotState
+    }
+
+    impl RunSummary {
+        /// Converts the summary to JSON
+        pub fn to_json(&self) -> Result<String, serde_json::Error> {
+            serde_json::to_string_pretty(self)
+        }
+    }
+
+    impl MinerRobot {
+        /// Ends the game for the given reason
+        ///
+        /// # Arguments
+        ///
+        /// * `reason` - why the game ends
+        ///
+        /// # Returns
+        ///
+        /// A bool stating whether the robot moved to the Finished state or not
+        pub fn finish(&mut self, reason: GameOverReason) -> bool {
+            if self.state == RobotState::Finished {
+                return false;
+            }
+            let message = reason.to_string();
+            self.game_over_reason = Some(reason);
+            self.transition_to(RobotState::Finished, &message)
+        }
+        /// Creates the summary of the run so far, the final state is the current state of the robot
+        pub fn get_run_summary(&self, reason: GameOverReason) -> RunSummary {
+            RunSummary {
+                name: self.name.clone(),
+                reason,
+                ticks: self.tick,
+                energy_used: self.energy_ledger.get_total_spent(),
+                energy_refilled: self.energy_ledger.get_total_refilled(),
+                rocks_mined: self.rocks_mined,
+                bridges_built: self.bridges_built,
+                tiles_discovered: self.coverage.discovered_tiles,
+                goals_completed: self.goals_completed,
+                final_state: self.state
+            }
+        }
+        /// Stores and logs the summary of the run
+        ///
+        /// # Arguments
+        ///
+        /// * `reason` - why the game ended
+        /// * `final_state` - the state the robot was in when the game ended
+        pub(crate) fn write_run_summary(&mut self, reason: GameOverReason, final_state: RobotState) {
+            let mut summary = self.get_run_summary(reason);
+            summary.final_state = final_state;
+            match summary.to_json() {
+                Ok(json) => info!(target: "state", "Run summary:\n{}", json),
+                Err(e) => info!(target: "state", "Run summary: {:?} ({})", summary, e)
+            }
+            self.run_summary = Some(summary);
+        }
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use super::*;
+
+        #[test]
+        fn summary_serialises_to_json() {
+            let summary = RunSummary {
+                name: String::from("miner"),
+                reason: GameOverReason::NotEnoughRocks { needed: 7, collected: 3 },
+                ticks: 120,
+                energy_used: 900,
+                energy_refilled: 0,
+                rocks_mined: 3,
+                bridges_built: 1,
+                tiles_discovered: 450,
+                goals_completed: 2,
+                final_state: RobotState::Exploring
+            };
+            let json: serde_json::Value = serde_json::from_str(&summary.to_json().unwrap()).unwrap();
+            assert_eq!(json["name"], "miner");
+            assert_eq!(json["reason"]["NotEnoughRocks"]["needed"], 7);
+            assert_eq!(json["reason"]["NotEnoughRocks"]["collected"], 3);
+            assert_eq!(json["ticks"], 120);
+            assert_eq!(json["energy_used"], 900);
+            assert_eq!(json["rocks_mined"], 3);
+            assert_eq!(json["bridges_built"], 1);
+            assert_eq!(json["tiles_discovered"], 450);
+            assert_eq!(json["goals_completed"], 2);
+            assert_eq!(json["final_state"], "Exploring");
+
+            let parsed: RunSummary = serde_json::from_str(&summary.to_json().unwrap()).unwrap();
+            assert_eq!(parsed, summary);
+        }
+
+        #[test]
+        fn final_state_is_the_state_before_finishing() {
+            let mut robot = MinerRobot::new();
+            robot.transition_to(RobotState::Recharging, "test");
+            assert!(robot.finish(GameOverReason::Stuck(String::from("test"))));
+            let summary = robot.run_summary.clone().unwrap();
+            assert_eq!(summary.final_state, RobotState::Recharging);
+            assert_eq!(summary.reason, GameOverReason::Stuck(String::from("test")));
+            assert!(!robot.finish(GameOverReason::NoReachableFrontier));
+        }
+    }
+}
@@ -0,0 +1,199 @@
+pub mod watchdog {
+    use std::collections::{HashMap, VecDeque};
+
+    // MinerRobot
+    use crate::{MinerRobot, RobotState};
+
+    // robotics lib
+    use robotics_lib::world::tile::Content;
+
+    /// What the robot looked like at the end of a tick
+    ///
+    /// # Notes
+    ///
+    /// The position is not sampled, walking around without collecting or discovering anything is no progress
+    #[derive(Debug, Clone, PartialEq)]
+    pub struct ProgressSample {
+        pub tick: usize,
+        pub backpack: HashMap<Content, usize>,
+        pub discovered_tiles: usize
+    }
+
+    /// Keeps the last samples of the robot and tells whether it stopped making progress
+    #[derive(Debug, Clone)]
+    pub struct Watchdog {
+        window: usize,
+        samples: VecDeque<ProgressSample>,
+        recoveries: usize
+    }
+
+    impl Watchdog {
+        /// Creates a watchdog
+        ///
+        /// # Arguments
+        ///
+        /// * `window` - the amount of ticks without progress after which the robot is stuck
+        pub fn new(window: usize) -> Self {
+            Self {
+                window,
+                samples: VecDeque::with_capacity(window),
+                recoveries: 0
+            }
+        }
+        /// Adds a sample to the window, the oldest one gets dropped once the window is full
+        ///
+        /// # Notes
+        ///
+        /// The recoveries are reset as soon as the robot collects something or discovers new tiles
+        pub fn record(&mut self, sample: ProgressSample) {
+            if let Some(last) = self.samples.back() {
+                if last.backpack != sample.backpack || last.discovered_tiles != sample.discovered_tiles {
+                    self.recoveries = 0;
+                }
+            }
+            self.samples.push_back(sample);
+            while self.samples.len() > self.window {
+                self.samples.pop_front();
+            }
+        }
+        /// Checks if the robot made no progress during the whole window
+        ///
+        /// # Returns
+        ///
+        /// A bool stating whether the robot is stuck or not
+        ///
+        /// # Notes
+        ///
+        /// The robot is stuck if its backpack and the discovered tiles didn't change, wherever it moved:
+        /// walking around without collecting or discovering anything is not progress
+        pub fn is_stalled(&self) -> bool {
+            let first = match self.samples.front() {
+                Some(first) if self.samples.len() >= self.window => first,
+                _ => return false
+            };
+            self.samples.iter().all(|s| s.backpack == first.backpack && s.discovered_tiles == first.discovered_tiles)
+        }
+        /// Starts a new recovery, the window is emptied so the recovery gets a whole window to make progress
+        pub fn trigger(&mut self) {
+            self.recoveries += 1;
+            self.samples.clear();
+        }
+        /// Empties the window, used while the robot is waiting on purpose
+        pub fn clear(&mut self) {
+            self.samples.clear();
+        }
+        /// Returns the amount of recoveries since the last progress
+        pub fn get_recoveries(&self) -> usize {
+            self.recoveries
+        }
+    }
+
+    impl MinerRobot {
+        /// Samples the robot and moves it to the Stuck state if it hasn't made progress for the whole window
+        ///
+        /// # Notes
+        ///
+        /// The ticks spent recharging are not sampled since the robot is waiting on purpose
+        pub(crate) fn check_progress(&mut self) {
+            match self.state {
+                RobotState::Recharging => {
+                    self.watchdog.clear();
+                    return;
+                },
+                RobotState::Stuck | RobotState::Finished => return,
+                _ => {}
+            }
+
+            self.watchdog.record(ProgressSample {
+                tick: self.tick,
+                backpack: self.robot.backpack.get_contents().clone(),
+                discovered_tiles: self.coverage.discovered_tiles
+            });
+            if self.watchdog.is_stalled() {
+                self.watchdog.trigger();
+                let reason = format!("no progress for {} ticks", self.config.watchdog_window);
+                self.transition_to(RobotState::Stuck, &reason);
+            }
+        }
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use super::*;
+
+        fn sample(tick: usize, rocks: usize, discovered_tiles: usize) -> ProgressSample {
+            ProgressSample {
+                tick,
+                backpack: HashMap::from([(Content::Rock(0), rocks)]),
+                discovered_tiles
+            }
+        }
+
+        #[test]
+        fn not_stalled_before_the_window_is_full() {
+            let mut watchdog = Watchdog::new(3);
+            watchdog.record(sample(0, 0, 10));
+            watchdog.record(sample(1, 0, 10));
+            assert!(!watchdog.is_stalled());
+            watchdog.record(sample(2, 0, 10));
+            assert!(watchdog.is_stalled());
+        }
+
+        #[test]
+        fn keeping_the_same_backpack_and_discoveries_is_stalled() {
+            let mut watchdog = Watchdog::new(4);
+            for tick in 0..4 {
+                watchdog.record(sample(tick, 2, 10));
+            }
+            assert!(watchdog.is_stalled());
+        }
+
+        #[test]
+        fn progress_inside_the_window_is_not_stalled() {
+            let mut watchdog = Watchdog::new(3);
+            watchdog.record(sample(0, 0, 10));
+            watchdog.record(sample(1, 1, 10));
+            watchdog.record(sample(2, 1, 10));
+            assert!(!watchdog.is_stalled());
+
+            // the progress leaves the window after three more ticks
+            watchdog.record(sample(3, 1, 10));
+            assert!(watchdog.is_stalled());
+
+            watchdog.record(sample(4, 1, 12));
+            assert!(!watchdog.is_stalled());
+        }
+
+        #[test]
+        fn trigger_counts_recoveries_until_progress() {
+            let mut watchdog = Watchdog::new(2);
+            watchdog.record(sample(0, 0, 10));
+            watchdog.record(sample(1, 0, 10));
+            watchdog.trigger();
+            assert_eq!(watchdog.get_recoveries(), 1);
+            // the recovery gets a whole window
+            assert!(!watchdog.is_stalled());
+
+            watchdog.record(sample(2, 0, 10));
+            watchdog.record(sample(3, 0, 10));
+            assert!(watchdog.is_stalled());
+            watchdog.trigger();
+            assert_eq!(watchdog.get_recoveries(), 2);
+
+            watchdog.record(sample(4, 0, 10));
+            watchdog.record(sample(5, 0, 11));
+            assert_eq!(watchdog.get_recoveries(), 0);
+        }
+
+        #[test]
+        fn clear_empties_the_window_but_keeps_the_recoveries() {
+            let mut watchdog = Watchdog::new(2);
+            watchdog.trigger();
+            watchdog.record(sample(0, 0, 10));
+            watchdog.record(sample(1, 0, 10));
+            watchdog.clear();
+            assert!(!watchdog.is_stalled());
+            assert_eq!(watchdog.get_recoveries(), 1);
+        }
+    }
+}
@@ -1,109 +1,69 @@
 pub mod world {
     // MinerRobot
-    use crate::{MinerRobot, World};
+    use crate::{KnownMap, MinerRobot, Pos, QuantityFilter, World};
 
     // robotics lib
-    use robotics_lib::interface::robot_map;
-    use robotics_lib::world::tile::{Content, Tile, TileType};
+    use robotics_lib::world::tile::Content;
     impl MinerRobot {
         /// Returns the robot's known map
         ///
-        /// # Arguments
-        ///
-        /// * `world` - the world
-        ///
         /// # Returns
         ///
-        /// The known world as a Vec<Vec<Tile>>
-        pub fn get_map(&self, world: &World) -> Vec<Vec<Tile>> {
-            let robot_map = robot_map(world).expect("Error while retrieving the map");
-            let world_dim = robot_map.len();
-            let default_tile = Tile {
-                tile_type: TileType::DeepWater,
-                content: Content::None,
-                elevation: 0
-            };
-            let mut map = vec![vec![default_tile; world_dim];world_dim];
-            for (i, row) in robot_map.iter().enumerate() {
-                for (j, tile) in row.iter().enumerate() {
-                    match tile {
-                        Some(t) => {
-                            map[i][j] = t.clone();
-                        }
-                        None => {
-                            map[i][j] = Tile {
-                                tile_type: TileType::DeepWater,
-                                content: Content::None,
-                                elevation: 0
-                            };
-                        }
-                    };
-                }
-            }
-            map
+        /// A reference to the KnownMap, refreshed at the beginning of every tick and after the spyglass scans,
+        /// the events of the other actions keep it up to date in between
+        pub fn get_known_map(&self) -> &KnownMap {
+            &self.known_map
         }
-        /// Returns the robot's known map with Option
+        /// Reads the robot's known map from the world again
         ///
         /// # Arguments
         ///
         /// * `world` - the world
+        pub fn refresh_known_map(&mut self, world: &World) {
+            self.known_map.refresh(world);
+        }
+        /// Returns the amount of tiles that the robot has discovered
+        ///
+        /// # Returns
+        ///
+        /// The amount of discovered tiles
+        pub fn count_discovered_tiles(&self) -> usize {
+            self.known_map.get_discovered_tiles()
+        }
+        /// Returns the ratio of the world that the robot has discovered
         ///
         /// # Returns
         ///
-        /// The known world as a Vec<Vec<Option<Tile>>>
-        pub fn get_map_option(&self, world: &World) -> Vec<Vec<Option<Tile>>> {
-            let robot_map = robot_map(world).expect("Error while retrieving the map");
-            let world_dim = robot_map.len();
-            let default_tile = Tile {
-                tile_type: TileType::DeepWater,
-                content: Content::None,
-                elevation: 0
-            };
-            let mut map: Vec<Vec<Option<Tile>>> = vec![vec![Some(default_tile); world_dim];world_dim];
-            for (i, row) in robot_map.iter().enumerate() {
-                for (j, tile) in row.iter().enumerate() {
-                    match tile {
-                        Some(t) => {
-                            map[i][j] = Some(t.clone());
-                        }
-                        None => {
-                            map[i][j] = Some(Tile {
-                                tile_type: TileType::DeepWater,
-                                content: Content::None,
-                                elevation: 0
-                            });
-                        }
-                    };
-                }
+        /// A value between 0 and 1
+        pub fn get_known_ratio(&self) -> f64 {
+            let total_tiles: usize = self.known_map.get_options().iter().map(|row| row.len()).sum();
+            if total_tiles == 0 {
+                return 0.0;
             }
-            map
+            self.count_discovered_tiles() as f64 / total_tiles as f64
         }
-        /// Returns all the coordinates of tiles that contain the given content
+        /// Returns the k nearest known tiles containing content of the given kind
         ///
         /// # Arguments
         ///
-        /// * `world` - the world
-        /// * `content` - the content to search for in the tiles
+        /// * `content` - the kind of content to search for, its quantity is ignored
+        /// * `k` - the maximum amount of tiles returned
+        /// * `radius` - the maximum Manhattan distance from the robot, None for no limit
+        /// * `filter` - the filter on the quantity of the content
         ///
         /// # Returns
         ///
-        /// A vector of tuples containing the coordinates of tiles with the given content
-        pub fn get_tiles_by_content(&self, world: &World, content: Content) -> Vec<(usize, usize)>{
-            let mut result = Vec::new();
-            for (i, row) in robot_map(world).unwrap().iter().enumerate() {
-                for (j, tile) in row.iter().enumerate() {
-                    match tile {
-                        Some(t) => {
-                            if t.content == content {
-                                result.push((i,j));
-                            }
-                        }
-                        None => {
-                        }
-                    };
-                }
-            }
-            result
+        /// A vector of tuples containing the coordinates of the tiles and the quantity of their content, the closest first
+        pub fn get_nearest_content(&self, content: &Content, k: usize, radius: Option<usize>, filter: QuantityFilter) -> Vec<(Pos, usize)> {
+            self.known_map.get_content_index().get_nearest(content, self.get_position(), k, radius, filter)
+        }
+        /// Returns the total quantity of the given kind of content in the known tiles
+        ///
+        /// # Arguments
+        ///
+        /// * `content` - the kind of content, its quantity is ignored
+        pub fn get_known_content_quantity(&self, content: &Content) -> usize {
+            self.known_map.get_content_index().get_total_quantity(content)
         }
     }
-}
\ No newline at end of file
+}